@@ -0,0 +1,274 @@
+use core::convert::Infallible;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+
+use crate::{array_from_fn, PartialArray};
+
+/// Checks at compile time that `A + B == C`
+struct AssertSum<const A: usize, const B: usize, const C: usize>;
+
+impl<const A: usize, const B: usize, const C: usize> AssertSum<A, B, C> {
+    const OK: () = assert!(A + B == C, "array lengths do not add up");
+}
+
+/// Checks at compile time that `A <= N`
+struct AssertFits<const A: usize, const N: usize>;
+
+impl<const A: usize, const N: usize> AssertFits<A, N> {
+    const OK: () = assert!(A <= N, "array length out of range");
+}
+
+/// Joins two arrays into a single array
+///
+/// The length of the output must be the sum of the lengths of the inputs, which is checked at
+/// compile time.
+///
+/// ```
+/// let joined: [u8; 5] = arrutil::concat_arrays([1, 2], [3, 4, 5]);
+/// assert_eq!(joined, [1, 2, 3, 4, 5]);
+/// ```
+///
+/// ```compile_fail
+/// let joined: [u8; 4] = arrutil::concat_arrays([1, 2], [3, 4, 5]);
+/// ```
+pub fn concat_arrays<T, const A: usize, const B: usize, const C: usize>(
+    head: [T; A],
+    tail: [T; B],
+) -> [T; C] {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertSum::<A, B, C>::OK;
+    let head = ManuallyDrop::new(head);
+    let tail = ManuallyDrop::new(tail);
+    let mut joined = MaybeUninit::<[T; C]>::uninit();
+    unsafe {
+        let output = joined.as_mut_ptr() as *mut T;
+        ptr::copy_nonoverlapping(head.as_ptr(), output, A);
+        ptr::copy_nonoverlapping(tail.as_ptr(), output.add(A), B);
+        joined.assume_init()
+    }
+}
+
+/// Splits an array into two arrays by value
+///
+/// The lengths of the outputs must sum to the length of the input, which is checked at compile
+/// time.
+///
+/// ```
+/// let (head, tail): ([u8; 2], [u8; 3]) = arrutil::split_array_owned([1, 2, 3, 4, 5]);
+/// assert_eq!(head, [1, 2]);
+/// assert_eq!(tail, [3, 4, 5]);
+/// ```
+///
+/// ```compile_fail
+/// let (head, tail): ([u8; 2], [u8; 2]) = arrutil::split_array_owned([1, 2, 3, 4, 5]);
+/// ```
+pub fn split_array_owned<T, const N: usize, const A: usize, const B: usize>(
+    source: [T; N],
+) -> ([T; A], [T; B]) {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertSum::<A, B, N>::OK;
+    let source = ManuallyDrop::new(source);
+    let start = source.as_ptr();
+    unsafe {
+        (
+            ptr::read(start as *const [T; A]),
+            ptr::read(start.add(A) as *const [T; B]),
+        )
+    }
+}
+
+/// Turns a reference to an array into a reference to a shorter array and a slice starting from the
+/// end of it
+///
+/// The length of the shorter array must not be greater than the length of the input, which is
+/// checked at compile time.
+///
+/// ```
+/// let (head, tail) = arrutil::split_array_ref::<_, 5, 2>(&[1, 2, 3, 4, 5]);
+/// assert_eq!(head, &[1, 2]);
+/// assert_eq!(tail, &[3, 4, 5]);
+/// ```
+///
+/// ```compile_fail
+/// let (head, tail) = arrutil::split_array_ref::<_, 5, 6>(&[1, 2, 3, 4, 5]);
+/// ```
+pub fn split_array_ref<T, const N: usize, const A: usize>(source: &[T; N]) -> (&[T; A], &[T]) {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertFits::<A, N>::OK;
+    let (head, tail) = source.split_at(A);
+    (unsafe { crate::slice_to_array_unchecked(head) }, tail)
+}
+
+/// Turns a mutable reference to an array into a mutable reference to a shorter array and a mutable
+/// slice starting from the end of it
+///
+/// The length of the shorter array must not be greater than the length of the input, which is
+/// checked at compile time.
+pub fn split_array_ref_mut<T, const N: usize, const A: usize>(
+    source: &mut [T; N],
+) -> (&mut [T; A], &mut [T]) {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertFits::<A, N>::OK;
+    let (head, tail) = source.split_at_mut(A);
+    (unsafe { crate::slice_to_array_mut_unchecked(head) }, tail)
+}
+
+/// Applies a function to each element of an array, returning an array of the results
+///
+/// If the function panics, the results built so far and the remaining elements are dropped before
+/// unwinding.
+pub fn map_array<T, U, const N: usize, F: FnMut(T) -> U>(source: [T; N], mut f: F) -> [U; N] {
+    match try_map_array(source, |value| Ok::<_, Infallible>(f(value))) {
+        Ok(output) => output,
+        Err(never) => match never {},
+    }
+}
+
+/// Applies a fallible function to each element of an array, stopping at the first error
+///
+/// If the function fails or panics, the results built so far and the remaining elements are
+/// dropped.
+pub fn try_map_array<T, U, E, const N: usize, F: FnMut(T) -> Result<U, E>>(
+    source: [T; N],
+    mut f: F,
+) -> Result<[U; N], E> {
+    let mut output = PartialArray::new();
+    for value in IntoIterator::into_iter(source) {
+        output.push_unchecked(f(value)?);
+    }
+    // SAFETY: the loop pushed one result for each of the `N` elements of `source` without
+    // returning early
+    Ok(unsafe { output.into_array_unchecked() })
+}
+
+/// Pairs up the elements of two arrays of the same length
+pub fn zip_arrays<A, B, const N: usize>(left: [A; N], right: [B; N]) -> [(A, B); N] {
+    let mut right = IntoIterator::into_iter(right);
+    map_array(left, |left| match right.next() {
+        Some(right) => (left, right),
+        None => unreachable!(),
+    })
+}
+
+/// Splits an array of pairs into two arrays
+pub fn unzip_array<A, B, const N: usize>(source: [(A, B); N]) -> ([A; N], [B; N]) {
+    let mut right = PartialArray::new();
+    let left = map_array(source, |(a, b)| {
+        right.push_unchecked(b);
+        a
+    });
+    // SAFETY: `map_array` returned, so it called the closure once for each of the `N` pairs
+    (left, unsafe { right.into_array_unchecked() })
+}
+
+/// Pairs each element of an array with its index
+pub fn enumerate_array<T, const N: usize>(source: [T; N]) -> [(usize, T); N] {
+    let mut index = 0;
+    map_array(source, |value| {
+        index += 1;
+        (index - 1, value)
+    })
+}
+
+/// Turns a reference to an array into an array of references to each element
+pub fn each_ref<T, const N: usize>(source: &[T; N]) -> [&T; N] {
+    array_from_fn(|index| &source[index])
+}
+
+/// Turns a mutable reference to an array into an array of mutable references to each element
+pub fn each_mut<T, const N: usize>(source: &mut [T; N]) -> [&mut T; N] {
+    let mut source = source.iter_mut();
+    array_from_fn(|_| match source.next() {
+        Some(value) => value,
+        None => unreachable!(),
+    })
+}
+
+#[test]
+fn concat_arrays_test() {
+    let joined: [String; 3] =
+        concat_arrays([String::from("a")], [String::from("b"), String::from("c")]);
+    assert_eq!(joined, ["a", "b", "c"]);
+    let joined: [u8; 2] = concat_arrays([], [1, 2]);
+    assert_eq!(joined, [1, 2]);
+}
+
+#[test]
+fn split_array_owned_test() {
+    let source = [String::from("a"), String::from("b"), String::from("c")];
+    let (head, tail): ([String; 1], [String; 2]) = split_array_owned(source);
+    assert_eq!(head, ["a"]);
+    assert_eq!(tail, ["b", "c"]);
+    let (head, tail): ([u8; 0], [u8; 2]) = split_array_owned([1, 2]);
+    assert_eq!((head, tail), ([], [1, 2]));
+}
+
+#[test]
+fn split_array_ref_test() {
+    let source = [1, 2, 3, 4, 5];
+    assert_eq!(
+        split_array_ref::<_, 5, 3>(&source),
+        (&[1, 2, 3], &source[3..])
+    );
+    assert_eq!(split_array_ref::<_, 5, 5>(&source), (&source, &source[5..]));
+}
+
+#[test]
+fn split_array_ref_mut_test() {
+    let mut source = [1, 2, 3, 4, 5];
+    {
+        let (head, tail) = split_array_ref_mut::<_, 5, 2>(&mut source);
+        head[1] = 20;
+        tail[0] = 30;
+    }
+    assert_eq!(source, [1, 20, 30, 4, 5]);
+}
+
+#[test]
+fn map_array_test() {
+    assert_eq!(map_array([1, 2, 3], |value| value * 2), [2, 4, 6]);
+    assert_eq!(map_array([1, 2], |value| value.to_string()), ["1", "2"]);
+    assert_eq!(map_array([0u8; 0], |value| value), []);
+}
+
+#[test]
+fn try_map_array_test() {
+    use std::rc::Rc;
+
+    let value = Rc::new(());
+    let source = [value.clone(), value.clone(), value.clone()];
+    let mut calls = 0;
+    let result = try_map_array(source, |value| {
+        calls += 1;
+        if calls == 2 {
+            Err("failed")
+        } else {
+            Ok(value)
+        }
+    });
+    assert_eq!(result, Err("failed"));
+    assert_eq!(Rc::strong_count(&value), 1);
+    assert_eq!(
+        try_map_array::<_, _, (), 3, _>(["1", "2", "3"], |s| s.parse::<u8>().map_err(drop)),
+        Ok([1, 2, 3])
+    );
+}
+
+#[test]
+fn zip_arrays_test() {
+    let zipped = zip_arrays([1, 2, 3], ["a", "b", "c"]);
+    assert_eq!(zipped, [(1, "a"), (2, "b"), (3, "c")]);
+    assert_eq!(unzip_array(zipped), ([1, 2, 3], ["a", "b", "c"]));
+    assert_eq!(enumerate_array(["a", "b"]), [(0, "a"), (1, "b")]);
+}
+
+#[test]
+fn each_ref_test() {
+    let mut source = [String::from("a"), String::from("b")];
+    let lengths = map_array(each_ref(&source), String::len);
+    assert_eq!(lengths, [1, 1]);
+    for value in each_mut(&mut source) {
+        value.push('!');
+    }
+    assert_eq!(source, ["a!", "b!"]);
+}
@@ -0,0 +1,81 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::ptr;
+
+/// Turns a boxed slice of exactly `N` elements into a boxed array without copying
+///
+/// Returns the original boxed slice if `N` is not equal to its length
+pub fn boxed_slice_to_array<T, const N: usize>(source: Box<[T]>) -> Result<Box<[T; N]>, Box<[T]>> {
+    if source.len() != N {
+        Err(source)
+    } else {
+        Ok(unsafe { Box::from_raw(Box::into_raw(source) as *mut [T; N]) })
+    }
+}
+
+/// Turns a vector of exactly `N` elements into a boxed array
+///
+/// The allocation is reused, although it is shrunk first if the vector has spare capacity.
+///
+/// Returns the original vector if `N` is not equal to its length
+pub fn vec_to_boxed_array<T, const N: usize>(source: Vec<T>) -> Result<Box<[T; N]>, Vec<T>> {
+    if source.len() != N {
+        Err(source)
+    } else {
+        Ok(unsafe { Box::from_raw(Box::into_raw(source.into_boxed_slice()) as *mut [T; N]) })
+    }
+}
+
+/// Moves the elements of a vector of exactly `N` elements into an array
+///
+/// Returns the original vector if `N` is not equal to its length
+pub fn vec_to_array<T, const N: usize>(mut source: Vec<T>) -> Result<[T; N], Vec<T>> {
+    if source.len() != N {
+        Err(source)
+    } else {
+        unsafe {
+            // The elements are moved out before the vector is dropped, so it must no longer own them
+            source.set_len(0);
+            Ok(ptr::read(source.as_ptr() as *const [T; N]))
+        }
+    }
+}
+
+#[test]
+fn boxed_slice_to_array_test() {
+    let source: Box<[u8]> = Box::new([1, 2, 3]);
+    let address = source.as_ptr();
+    let array: Box<[u8; 3]> = boxed_slice_to_array(source).unwrap();
+    assert_eq!(*array, [1, 2, 3]);
+    assert_eq!(array.as_ptr(), address);
+
+    let source: Box<[u8]> = Box::new([1, 2, 3]);
+    assert_eq!(
+        boxed_slice_to_array::<_, 2>(source),
+        Err(Box::from(&[1, 2, 3][..]))
+    );
+}
+
+#[test]
+fn vec_to_boxed_array_test() {
+    let source = vec![String::from("a"), String::from("b")];
+    let address = source.as_ptr();
+    let array: Box<[String; 2]> = vec_to_boxed_array(source).unwrap();
+    assert_eq!(*array, [String::from("a"), String::from("b")]);
+    assert_eq!(array.as_ptr(), address);
+
+    assert_eq!(
+        vec_to_boxed_array::<_, 4>(vec![1, 2, 3]),
+        Err(vec![1, 2, 3])
+    );
+}
+
+#[test]
+fn vec_to_array_test() {
+    let source = vec![String::from("a"), String::from("b")];
+    let array: [String; 2] = vec_to_array(source).unwrap();
+    assert_eq!(array, [String::from("a"), String::from("b")]);
+
+    assert_eq!(vec_to_array::<_, 2>(vec![1, 2, 3]), Err(vec![1, 2, 3]));
+    assert_eq!(vec_to_array::<u8, 0>(Vec::new()), Ok([]));
+}
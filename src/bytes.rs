@@ -0,0 +1,269 @@
+use crate::{
+    try_split_to_array_mut_scan, try_split_to_array_scan, ArrayReader, ArrayWriter, LengthError,
+};
+
+macro_rules! read_byte {
+    ($($ty:ident: $read:ident;)*) => {
+        $(
+            #[doc = concat!("Reads a `", stringify!($ty), "` from the start of a slice and mutates")]
+            #[doc = "the original slice to the end of the value"]
+            ///
+            /// Returns a [`LengthError`] if the slice is empty
+            pub fn $read(source: &mut &[u8]) -> Result<$ty, LengthError> {
+                try_split_to_array_scan(source).map(|bytes| $ty::from_ne_bytes(*bytes))
+            }
+        )*
+
+        impl ArrayReader<'_, u8> {
+            $(
+                #[doc = concat!("Reads a `", stringify!($ty), "`, advancing the reader")]
+                ///
+                /// Returns a [`LengthError`] if there are no elements left to read, in which case
+                /// the reader is not advanced
+                pub fn $read(&mut self) -> Result<$ty, LengthError> {
+                    self.try_take().map(|bytes| $ty::from_ne_bytes(*bytes))
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! read_endian {
+    ($($ty:ident: $le:ident, $be:ident, $ne:ident;)*) => {
+        $(
+            read_endian!(@fn $ty, $le, from_le_bytes, "little endian");
+            read_endian!(@fn $ty, $be, from_be_bytes, "big endian");
+            read_endian!(@fn $ty, $ne, from_ne_bytes, "native endian");
+        )*
+
+        impl ArrayReader<'_, u8> {
+            $(
+                read_endian!(@method $ty, $le, from_le_bytes, "little endian");
+                read_endian!(@method $ty, $be, from_be_bytes, "big endian");
+                read_endian!(@method $ty, $ne, from_ne_bytes, "native endian");
+            )*
+        }
+    };
+    (@fn $ty:ident, $read:ident, $from:ident, $endian:literal) => {
+        #[doc = concat!("Reads a ", $endian, " `", stringify!($ty), "` from the start of a slice")]
+        #[doc = "and mutates the original slice to the end of the value"]
+        ///
+        #[doc = concat!(
+            "Returns a [`LengthError`] if the slice is shorter than a `",
+            stringify!($ty),
+            "`"
+        )]
+        pub fn $read(source: &mut &[u8]) -> Result<$ty, LengthError> {
+            try_split_to_array_scan(source).map(|bytes| $ty::$from(*bytes))
+        }
+    };
+    (@method $ty:ident, $read:ident, $from:ident, $endian:literal) => {
+        #[doc = concat!("Reads a ", $endian, " `", stringify!($ty), "`, advancing the reader")]
+        ///
+        #[doc = concat!(
+            "Returns a [`LengthError`] if the remaining slice is shorter than a `",
+            stringify!($ty),
+            "`, in which case the reader is not advanced"
+        )]
+        pub fn $read(&mut self) -> Result<$ty, LengthError> {
+            self.try_take().map(|bytes| $ty::$from(*bytes))
+        }
+    };
+}
+
+macro_rules! write_byte {
+    ($($ty:ident: $write:ident;)*) => {
+        $(
+            #[doc = concat!("Writes a `", stringify!($ty), "` to the start of a mutable slice and")]
+            #[doc = "mutates the original slice to the end of the value"]
+            ///
+            /// Returns a [`LengthError`] if the slice is empty, in which case nothing is written
+            pub fn $write(source: &mut &mut [u8], value: $ty) -> Result<(), LengthError> {
+                *try_split_to_array_mut_scan(source)? = value.to_ne_bytes();
+                Ok(())
+            }
+        )*
+
+        impl ArrayWriter<'_, u8> {
+            $(
+                #[doc = concat!("Writes a `", stringify!($ty), "`, advancing the writer")]
+                ///
+                /// Returns a [`LengthError`] if there are no elements left to write, in which case
+                /// the writer is not advanced
+                pub fn $write(&mut self, value: $ty) -> Result<(), LengthError> {
+                    *self.try_take()? = value.to_ne_bytes();
+                    Ok(())
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! write_endian {
+    ($($ty:ident: $le:ident, $be:ident, $ne:ident;)*) => {
+        $(
+            write_endian!(@fn $ty, $le, to_le_bytes, "little endian");
+            write_endian!(@fn $ty, $be, to_be_bytes, "big endian");
+            write_endian!(@fn $ty, $ne, to_ne_bytes, "native endian");
+        )*
+
+        impl ArrayWriter<'_, u8> {
+            $(
+                write_endian!(@method $ty, $le, to_le_bytes, "little endian");
+                write_endian!(@method $ty, $be, to_be_bytes, "big endian");
+                write_endian!(@method $ty, $ne, to_ne_bytes, "native endian");
+            )*
+        }
+    };
+    (@fn $ty:ident, $write:ident, $to:ident, $endian:literal) => {
+        #[doc = concat!("Writes a ", $endian, " `", stringify!($ty), "` to the start of a mutable")]
+        #[doc = "slice and mutates the original slice to the end of the value"]
+        ///
+        #[doc = concat!(
+            "Returns a [`LengthError`] if the slice is shorter than a `",
+            stringify!($ty),
+            "`, in which case nothing is written"
+        )]
+        pub fn $write(source: &mut &mut [u8], value: $ty) -> Result<(), LengthError> {
+            *try_split_to_array_mut_scan(source)? = value.$to();
+            Ok(())
+        }
+    };
+    (@method $ty:ident, $write:ident, $to:ident, $endian:literal) => {
+        #[doc = concat!("Writes a ", $endian, " `", stringify!($ty), "`, advancing the writer")]
+        ///
+        #[doc = concat!(
+            "Returns a [`LengthError`] if the remaining slice is shorter than a `",
+            stringify!($ty),
+            "`, in which case the writer is not advanced"
+        )]
+        pub fn $write(&mut self, value: $ty) -> Result<(), LengthError> {
+            *self.try_take()? = value.$to();
+            Ok(())
+        }
+    };
+}
+
+read_byte! {
+    u8: read_u8;
+    i8: read_i8;
+}
+
+read_endian! {
+    u16: read_u16_le, read_u16_be, read_u16_ne;
+    u32: read_u32_le, read_u32_be, read_u32_ne;
+    u64: read_u64_le, read_u64_be, read_u64_ne;
+    u128: read_u128_le, read_u128_be, read_u128_ne;
+    i16: read_i16_le, read_i16_be, read_i16_ne;
+    i32: read_i32_le, read_i32_be, read_i32_ne;
+    i64: read_i64_le, read_i64_be, read_i64_ne;
+    i128: read_i128_le, read_i128_be, read_i128_ne;
+    f32: read_f32_le, read_f32_be, read_f32_ne;
+    f64: read_f64_le, read_f64_be, read_f64_ne;
+}
+
+write_byte! {
+    u8: write_u8;
+    i8: write_i8;
+}
+
+write_endian! {
+    u16: write_u16_le, write_u16_be, write_u16_ne;
+    u32: write_u32_le, write_u32_be, write_u32_ne;
+    u64: write_u64_le, write_u64_be, write_u64_ne;
+    u128: write_u128_le, write_u128_be, write_u128_ne;
+    i16: write_i16_le, write_i16_be, write_i16_ne;
+    i32: write_i32_le, write_i32_be, write_i32_ne;
+    i64: write_i64_le, write_i64_be, write_i64_ne;
+    i128: write_i128_le, write_i128_be, write_i128_ne;
+    f32: write_f32_le, write_f32_be, write_f32_ne;
+    f64: write_f64_le, write_f64_be, write_f64_ne;
+}
+
+#[test]
+fn read_test() {
+    let source = [0xff, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+    let source_ref = &mut &source[..];
+    assert_eq!(read_i8(source_ref), Ok(-1));
+    assert_eq!(read_u16_le(source_ref), Ok(0x0201));
+    assert_eq!(read_u16_be(source_ref), Ok(0x0304));
+    assert_eq!(read_u32_be(source_ref), Err(LengthError::new(4, 2)));
+    assert_eq!(
+        read_i16_ne(source_ref),
+        Ok(i16::from_ne_bytes([0x05, 0x06]))
+    );
+    assert_eq!(read_u8(source_ref), Err(LengthError::new(1, 0)));
+}
+
+#[test]
+fn read_wide_test() {
+    let mut source = Vec::new();
+    source.extend_from_slice(&(-2i64).to_be_bytes());
+    source.extend_from_slice(&u128::MAX.to_le_bytes());
+    source.extend_from_slice(&1.5f32.to_le_bytes());
+    source.extend_from_slice(&(-0.25f64).to_be_bytes());
+    let source_ref = &mut &source[..];
+    assert_eq!(read_i64_be(source_ref), Ok(-2));
+    assert_eq!(read_u128_le(source_ref), Ok(u128::MAX));
+    assert_eq!(read_f32_le(source_ref), Ok(1.5));
+    assert_eq!(read_f64_be(source_ref), Ok(-0.25));
+    assert!(source_ref.is_empty());
+}
+
+#[test]
+fn array_reader_read_test() {
+    let source = [0x01, 0x02, 0x03, 0x04, 0x05];
+    let mut reader = ArrayReader::new(&source[..]);
+    assert_eq!(reader.read_u32_le(), Ok(0x04030201));
+    assert_eq!(reader.read_u16_be(), Err(LengthError::new(2, 1).at(4)));
+    assert_eq!(reader.position(), 4);
+    assert_eq!(reader.read_u8(), Ok(0x05));
+}
+
+#[test]
+fn write_test() {
+    let mut source = [0; 7];
+    {
+        let source_ref = &mut &mut source[..];
+        assert_eq!(write_i8(source_ref, -1), Ok(()));
+        assert_eq!(write_u16_le(source_ref, 0x0201), Ok(()));
+        assert_eq!(write_u16_be(source_ref, 0x0304), Ok(()));
+        assert_eq!(write_u32_be(source_ref, 0), Err(LengthError::new(4, 2)));
+        assert_eq!(write_i16_ne(source_ref, 0x0605), Ok(()));
+        assert_eq!(write_u8(source_ref, 0), Err(LengthError::new(1, 0)));
+    }
+    let mut expected = [0xff, 0x01, 0x02, 0x03, 0x04, 0x00, 0x00];
+    expected[5..].copy_from_slice(&0x0605i16.to_ne_bytes());
+    assert_eq!(source, expected);
+}
+
+#[test]
+fn write_read_round_trip_test() {
+    let mut source = [0; 8 + 16 + 4 + 8];
+    {
+        let source_ref = &mut &mut source[..];
+        write_i64_be(source_ref, -2).unwrap();
+        write_u128_le(source_ref, u128::MAX).unwrap();
+        write_f32_le(source_ref, 1.5).unwrap();
+        write_f64_be(source_ref, -0.25).unwrap();
+        assert!(source_ref.is_empty());
+    }
+    let source_ref = &mut &source[..];
+    assert_eq!(read_i64_be(source_ref), Ok(-2));
+    assert_eq!(read_u128_le(source_ref), Ok(u128::MAX));
+    assert_eq!(read_f32_le(source_ref), Ok(1.5));
+    assert_eq!(read_f64_be(source_ref), Ok(-0.25));
+}
+
+#[test]
+fn array_writer_write_test() {
+    let mut source = [0; 5];
+    {
+        let mut writer = ArrayWriter::new(&mut source[..]);
+        assert_eq!(writer.write_u32_le(0x04030201), Ok(()));
+        assert_eq!(writer.write_u16_be(0), Err(LengthError::new(2, 1).at(4)));
+        assert_eq!(writer.written(), 4);
+        assert_eq!(writer.write_u8(0x05), Ok(()));
+    }
+    assert_eq!(source, [0x01, 0x02, 0x03, 0x04, 0x05]);
+}
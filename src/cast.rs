@@ -0,0 +1,176 @@
+use core::{fmt, mem, ptr};
+
+use crate::LengthError;
+
+/// Plain old data types, which can be reinterpreted from any sequence of bytes
+///
+/// # Safety
+///
+/// Every bit pattern of `size_of::<Self>()` bytes must be a valid value of the type, and the type
+/// must not contain padding or interior mutability
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($ty:ty),*) => {
+        $(unsafe impl Pod for $ty {})*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+/// The error returned when a byte slice cannot be reinterpreted as a reference to an array
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CastError {
+    /// The slice has fewer bytes than the array, both lengths are in bytes
+    Length(LengthError),
+    /// The slice does not start at an address that is a multiple of the element alignment
+    Alignment {
+        /// The alignment of the array element type
+        align: usize,
+    },
+}
+
+impl From<LengthError> for CastError {
+    fn from(error: LengthError) -> Self {
+        CastError::Length(error)
+    }
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CastError::Length(error) => write!(
+                f,
+                "slice of {} bytes cannot be cast to an array of {} bytes",
+                error.actual, error.expected
+            ),
+            CastError::Alignment { align } => {
+                write!(f, "slice is not aligned to a multiple of {} bytes", align)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CastError {}
+
+fn check_cast<U: Pod, const N: usize>(source: &[u8]) -> Result<(), CastError> {
+    let len = mem::size_of::<[U; N]>();
+    if source.len() < len {
+        Err(LengthError::new(len, source.len()).into())
+    } else if source.as_ptr() as usize % mem::align_of::<U>() != 0 {
+        Err(CastError::Alignment {
+            align: mem::align_of::<U>(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Reinterprets the start of a byte slice as a reference to an array of plain old data
+///
+/// Any bytes after the first `N * size_of::<U>()` are ignored. Use [`cast_array_unaligned`] to copy
+/// from a slice that may not be aligned.
+///
+/// Returns a [`CastError`] if the slice is too short or is not aligned for `U`
+pub fn cast_array<U: Pod, const N: usize>(source: &[u8]) -> Result<&[U; N], CastError> {
+    check_cast::<U, N>(source)?;
+    Ok(unsafe { &*(source.as_ptr() as *const [U; N]) })
+}
+
+/// Reinterprets the start of a mutable byte slice as a mutable reference to an array of plain old
+/// data
+///
+/// Any bytes after the first `N * size_of::<U>()` are ignored.
+///
+/// Returns a [`CastError`] if the slice is too short or is not aligned for `U`
+pub fn cast_array_mut<U: Pod, const N: usize>(source: &mut [u8]) -> Result<&mut [U; N], CastError> {
+    check_cast::<U, N>(source)?;
+    Ok(unsafe { &mut *(source.as_mut_ptr() as *mut [U; N]) })
+}
+
+/// Copies the start of a byte slice into an array of plain old data, regardless of alignment
+///
+/// Any bytes after the first `N * size_of::<U>()` are ignored.
+///
+/// Returns a [`CastError`] if the slice is too short
+pub fn cast_array_unaligned<U: Pod, const N: usize>(source: &[u8]) -> Result<[U; N], CastError> {
+    let len = mem::size_of::<[U; N]>();
+    if source.len() < len {
+        Err(LengthError::new(len, source.len()).into())
+    } else {
+        Ok(unsafe { ptr::read_unaligned(source.as_ptr() as *const [U; N]) })
+    }
+}
+
+#[cfg(test)]
+#[repr(align(8))]
+struct Aligned([u8; 16]);
+
+#[test]
+fn cast_array_test() {
+    let source = Aligned([1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0]);
+    let expected = [1u32, 2, 3, 4].map(u32::from_le);
+    assert_eq!(cast_array::<u32, 4>(&source.0[..]), Ok(&expected));
+    assert_eq!(
+        cast_array::<u32, 2>(&source.0[8..]),
+        Ok(&[expected[2], expected[3]])
+    );
+    assert_eq!(
+        cast_array::<[u32; 2], 1>(&source.0[..]),
+        Ok(&[[expected[0], expected[1]]])
+    );
+    assert_eq!(
+        cast_array::<u32, 4>(&source.0[4..]),
+        Err(CastError::Length(LengthError::new(16, 12)))
+    );
+    assert_eq!(
+        cast_array::<u32, 2>(&source.0[1..]),
+        Err(CastError::Alignment { align: 4 })
+    );
+    assert_eq!(cast_array::<u32, 0>(&source.0[..]), Ok(&[]));
+}
+
+#[test]
+fn cast_array_mut_test() {
+    let mut source = Aligned([0; 16]);
+    {
+        let array: &mut [u16; 8] = cast_array_mut(&mut source.0[..]).unwrap();
+        array[1] = u16::from_ne_bytes([5, 6]);
+    }
+    assert_eq!(source.0[..4], [0, 0, 5, 6]);
+    assert_eq!(
+        cast_array_mut::<u16, 2>(&mut source.0[3..]),
+        Err(CastError::Alignment { align: 2 })
+    );
+}
+
+#[test]
+fn cast_array_unaligned_test() {
+    let source = Aligned([9, 1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(
+        cast_array_unaligned::<u32, 2>(&source.0[1..]),
+        Ok([
+            u32::from_ne_bytes([1, 2, 3, 4]),
+            u32::from_ne_bytes([5, 6, 7, 8])
+        ])
+    );
+    assert_eq!(
+        cast_array_unaligned::<u64, 2>(&source.0[1..]),
+        Err(CastError::Length(LengthError::new(16, 15)))
+    );
+}
+
+#[test]
+fn cast_error_display_test() {
+    assert_eq!(
+        CastError::Alignment { align: 4 }.to_string(),
+        "slice is not aligned to a multiple of 4 bytes"
+    );
+    assert_eq!(
+        CastError::from(LengthError::new(8, 3)).to_string(),
+        "slice of 3 bytes cannot be cast to an array of 8 bytes"
+    );
+}
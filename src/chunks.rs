@@ -0,0 +1,358 @@
+use core::iter::FusedIterator;
+use core::slice;
+
+use crate::{
+    split_to_array_end_scan, split_to_array_mut_end_scan, split_to_array_mut_scan,
+    split_to_array_scan,
+};
+
+/// Returns an iterator over references to `N` element arrays of a slice, starting at the beginning
+///
+/// Elements at the end of the slice which do not fill an array are available through
+/// [`ArrayChunks::remainder`].
+///
+/// # Panics
+///
+/// Panics if `N` is zero
+pub fn array_chunks<T, const N: usize>(source: &[T]) -> ArrayChunks<'_, T, N> {
+    assert!(N != 0, "chunk size must be non-zero");
+    let (chunks, remainder) = source.split_at(source.len() - source.len() % N);
+    ArrayChunks { chunks, remainder }
+}
+
+/// Returns an iterator over mutable references to `N` element arrays of a slice, starting at the
+/// beginning
+///
+/// Elements at the end of the slice which do not fill an array are available through
+/// [`ArrayChunksMut::into_remainder`].
+///
+/// # Panics
+///
+/// Panics if `N` is zero
+pub fn array_chunks_mut<T, const N: usize>(source: &mut [T]) -> ArrayChunksMut<'_, T, N> {
+    assert!(N != 0, "chunk size must be non-zero");
+    let len = source.len();
+    let (chunks, remainder) = source.split_at_mut(len - len % N);
+    ArrayChunksMut { chunks, remainder }
+}
+
+/// Returns an iterator over references to `N` element arrays of a slice, starting at the end
+///
+/// Elements at the start of the slice which do not fill an array are available through
+/// [`ArrayRChunks::remainder`].
+///
+/// # Panics
+///
+/// Panics if `N` is zero
+pub fn array_rchunks<T, const N: usize>(source: &[T]) -> ArrayRChunks<'_, T, N> {
+    assert!(N != 0, "chunk size must be non-zero");
+    let (remainder, chunks) = source.split_at(source.len() % N);
+    ArrayRChunks { chunks, remainder }
+}
+
+/// An iterator over references to `N` element arrays of a slice, starting at the beginning
+///
+/// Created by [`array_chunks`].
+#[derive(Debug)]
+pub struct ArrayChunks<'a, T, const N: usize> {
+    chunks: &'a [T],
+    remainder: &'a [T],
+}
+
+impl<'a, T, const N: usize> ArrayChunks<'a, T, N> {
+    /// The elements at the end of the slice which do not fill an array
+    pub fn remainder(&self) -> &'a [T] {
+        self.remainder
+    }
+}
+
+impl<T, const N: usize> Clone for ArrayChunks<'_, T, N> {
+    fn clone(&self) -> Self {
+        ArrayChunks { ..*self }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ArrayChunks<'a, T, N> {
+    type Item = &'a [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_to_array_scan(&mut self.chunks)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.chunks.len() / N;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ArrayChunks<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        split_to_array_end_scan(&mut self.chunks)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayChunks<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for ArrayChunks<'_, T, N> {}
+
+/// An iterator over mutable references to `N` element arrays of a slice, starting at the beginning
+///
+/// Created by [`array_chunks_mut`].
+#[derive(Debug)]
+pub struct ArrayChunksMut<'a, T, const N: usize> {
+    chunks: &'a mut [T],
+    remainder: &'a mut [T],
+}
+
+impl<'a, T, const N: usize> ArrayChunksMut<'a, T, N> {
+    /// Consumes the iterator, returning the elements at the end of the slice which do not fill an
+    /// array
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.remainder
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ArrayChunksMut<'a, T, N> {
+    type Item = &'a mut [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_to_array_mut_scan(&mut self.chunks)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.chunks.len() / N;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ArrayChunksMut<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        split_to_array_mut_end_scan(&mut self.chunks)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayChunksMut<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for ArrayChunksMut<'_, T, N> {}
+
+/// An iterator over references to `N` element arrays of a slice, starting at the end
+///
+/// Created by [`array_rchunks`].
+#[derive(Debug)]
+pub struct ArrayRChunks<'a, T, const N: usize> {
+    chunks: &'a [T],
+    remainder: &'a [T],
+}
+
+impl<'a, T, const N: usize> ArrayRChunks<'a, T, N> {
+    /// The elements at the start of the slice which do not fill an array
+    pub fn remainder(&self) -> &'a [T] {
+        self.remainder
+    }
+}
+
+impl<T, const N: usize> Clone for ArrayRChunks<'_, T, N> {
+    fn clone(&self) -> Self {
+        ArrayRChunks { ..*self }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ArrayRChunks<'a, T, N> {
+    type Item = &'a [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_to_array_end_scan(&mut self.chunks)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.chunks.len() / N;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ArrayRChunks<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        split_to_array_scan(&mut self.chunks)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayRChunks<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for ArrayRChunks<'_, T, N> {}
+
+/// Turns a slice into a slice of `M` element arrays, starting at the beginning, and a slice of the
+/// elements at the end which do not fill an array
+///
+/// # Panics
+///
+/// Panics if `M` is zero
+pub fn as_chunks<T, const M: usize>(source: &[T]) -> (&[[T; M]], &[T]) {
+    assert!(M != 0, "chunk size must be non-zero");
+    let (chunks, remainder) = source.split_at(source.len() - source.len() % M);
+    let chunks =
+        unsafe { slice::from_raw_parts(chunks.as_ptr() as *const [T; M], chunks.len() / M) };
+    (chunks, remainder)
+}
+
+/// Turns a slice into a slice of the elements at the start which do not fill an array, and a slice
+/// of `M` element arrays ending at the end
+///
+/// # Panics
+///
+/// Panics if `M` is zero
+pub fn as_rchunks<T, const M: usize>(source: &[T]) -> (&[T], &[[T; M]]) {
+    assert!(M != 0, "chunk size must be non-zero");
+    let (remainder, chunks) = source.split_at(source.len() % M);
+    let chunks =
+        unsafe { slice::from_raw_parts(chunks.as_ptr() as *const [T; M], chunks.len() / M) };
+    (remainder, chunks)
+}
+
+/// Turns a mutable slice into a mutable slice of `M` element arrays, starting at the beginning, and
+/// a mutable slice of the elements at the end which do not fill an array
+///
+/// # Panics
+///
+/// Panics if `M` is zero
+pub fn as_chunks_mut<T, const M: usize>(source: &mut [T]) -> (&mut [[T; M]], &mut [T]) {
+    assert!(M != 0, "chunk size must be non-zero");
+    let len = source.len();
+    let (chunks, remainder) = source.split_at_mut(len - len % M);
+    let chunks =
+        unsafe { slice::from_raw_parts_mut(chunks.as_mut_ptr() as *mut [T; M], chunks.len() / M) };
+    (chunks, remainder)
+}
+
+/// Turns a mutable slice into a mutable slice of the elements at the start which do not fill an
+/// array, and a mutable slice of `M` element arrays ending at the end
+///
+/// # Panics
+///
+/// Panics if `M` is zero
+pub fn as_rchunks_mut<T, const M: usize>(source: &mut [T]) -> (&mut [T], &mut [[T; M]]) {
+    assert!(M != 0, "chunk size must be non-zero");
+    let len = source.len();
+    let (remainder, chunks) = source.split_at_mut(len % M);
+    let chunks =
+        unsafe { slice::from_raw_parts_mut(chunks.as_mut_ptr() as *mut [T; M], chunks.len() / M) };
+    (remainder, chunks)
+}
+
+/// Turns a slice of `M` element arrays into a slice of their elements
+///
+/// # Panics
+///
+/// Panics if the length of the output overflows, which can only happen for zero sized types
+pub fn flatten<T, const M: usize>(source: &[[T; M]]) -> &[T] {
+    let len = source
+        .len()
+        .checked_mul(M)
+        .expect("flattened slice length overflows");
+    unsafe { slice::from_raw_parts(source.as_ptr() as *const T, len) }
+}
+
+/// Turns a mutable slice of `M` element arrays into a mutable slice of their elements
+///
+/// # Panics
+///
+/// Panics if the length of the output overflows, which can only happen for zero sized types
+pub fn flatten_mut<T, const M: usize>(source: &mut [[T; M]]) -> &mut [T] {
+    let len = source
+        .len()
+        .checked_mul(M)
+        .expect("flattened slice length overflows");
+    unsafe { slice::from_raw_parts_mut(source.as_mut_ptr() as *mut T, len) }
+}
+
+#[test]
+fn array_chunks_test() {
+    let source = [1, 2, 3, 4, 5, 6, 7];
+    let mut chunks = array_chunks::<_, 2>(&source[..]);
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks.remainder(), &[7]);
+    assert_eq!(chunks.next(), Some(&[1, 2]));
+    assert_eq!(chunks.next_back(), Some(&[5, 6]));
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks.clone().next(), Some(&[3, 4]));
+    assert_eq!(chunks.next(), Some(&[3, 4]));
+    assert_eq!(chunks.next(), None);
+    assert_eq!(chunks.next_back(), None);
+
+    assert_eq!(array_chunks::<_, 8>(&source[..]).count(), 0);
+    assert_eq!(array_chunks::<_, 8>(&source[..]).remainder(), &source[..]);
+}
+
+#[test]
+fn array_chunks_mut_test() {
+    let mut source = [1, 2, 3, 4, 5, 6, 7];
+    {
+        let mut chunks = array_chunks_mut::<_, 3>(&mut source[..]);
+        assert_eq!(chunks.len(), 2);
+        chunks.next_back().unwrap()[0] = 40;
+        for chunk in &mut chunks {
+            chunk[2] = 30;
+        }
+        chunks.into_remainder()[0] = 70;
+    }
+    assert_eq!(source, [1, 2, 30, 40, 5, 6, 70]);
+}
+
+#[test]
+fn array_rchunks_test() {
+    let source = [1, 2, 3, 4, 5, 6, 7];
+    let mut chunks = array_rchunks::<_, 3>(&source[..]);
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks.remainder(), &[1]);
+    assert_eq!(chunks.next(), Some(&[5, 6, 7]));
+    assert_eq!(chunks.next_back(), Some(&[2, 3, 4]));
+    assert_eq!(chunks.next(), None);
+
+    let reversed: Vec<_> = array_rchunks::<_, 2>(&source[..]).rev().collect();
+    assert_eq!(reversed, [&[2, 3], &[4, 5], &[6, 7]]);
+}
+
+#[test]
+#[should_panic]
+fn array_chunks_zero_test() {
+    array_chunks::<u8, 0>(&[1, 2, 3]);
+}
+
+#[test]
+fn as_chunks_test() {
+    let source = [1, 2, 3, 4, 5, 6, 7];
+    assert_eq!(
+        as_chunks::<_, 3>(&source[..]),
+        (&[[1, 2, 3], [4, 5, 6]][..], &[7][..])
+    );
+    assert_eq!(
+        as_rchunks::<_, 3>(&source[..]),
+        (&[1][..], &[[2, 3, 4], [5, 6, 7]][..])
+    );
+    assert_eq!(as_chunks::<_, 8>(&source[..]), (&[][..], &source[..]));
+}
+
+#[test]
+fn as_chunks_mut_test() {
+    let mut source = [1, 2, 3, 4, 5, 6, 7];
+    {
+        let (rows, remainder) = as_chunks_mut::<_, 2>(&mut source[..]);
+        rows[1][0] = 30;
+        remainder[0] = 70;
+    }
+    {
+        let (remainder, rows) = as_rchunks_mut::<_, 2>(&mut source[..]);
+        rows[0][1] = 300;
+        remainder[0] = 10;
+    }
+    assert_eq!(source, [10, 2, 300, 4, 5, 6, 70]);
+}
+
+#[test]
+fn flatten_test() {
+    let mut rows = [[1, 2], [3, 4], [5, 6]];
+    assert_eq!(flatten(&rows), &[1, 2, 3, 4, 5, 6]);
+    flatten_mut(&mut rows)[3] = 40;
+    assert_eq!(rows, [[1, 2], [3, 40], [5, 6]]);
+    assert_eq!(flatten::<u8, 0>(&[[], []]), &[]);
+    assert_eq!(as_chunks::<_, 2>(flatten(&rows)).0, &rows);
+}
@@ -0,0 +1,109 @@
+use core::fmt;
+
+use crate::PartialArray;
+
+/// Collects the first `N` items of an iterator into an array
+///
+/// Any items after the first `N` are left in the iterator, pass it with
+/// [`Iterator::by_ref`] to continue using them, or use [`collect_array_exact`] to reject them.
+///
+/// Returns the items that were produced as a [`PartialArray`] if the iterator has fewer than `N`
+/// items
+pub fn collect_array<I: IntoIterator, const N: usize>(
+    iter: I,
+) -> Result<[I::Item; N], PartialArray<I::Item, N>> {
+    let mut partial = PartialArray::new();
+    let mut iter = iter.into_iter();
+    while !partial.is_full() {
+        match iter.next() {
+            Some(value) => partial.push_unchecked(value),
+            None => return Err(partial),
+        }
+    }
+    Ok(unsafe { partial.into_array_unchecked() })
+}
+
+/// Collects an iterator of exactly `N` items into an array
+///
+/// Returns a [`CollectError`] if the iterator has fewer or more than `N` items
+pub fn collect_array_exact<I: IntoIterator, const N: usize>(
+    iter: I,
+) -> Result<[I::Item; N], CollectError<I::Item, N>> {
+    let mut iter = iter.into_iter();
+    let array = collect_array(&mut iter).map_err(CollectError::TooShort)?;
+    match iter.next() {
+        Some(_) => Err(CollectError::TooLong(array)),
+        None => Ok(array),
+    }
+}
+
+/// The error returned when an iterator does not have exactly the number of items needed to fill an
+/// array
+#[derive(Debug, PartialEq, Eq)]
+pub enum CollectError<T, const N: usize> {
+    /// The iterator ran out of items, with the items it produced
+    TooShort(PartialArray<T, N>),
+    /// The iterator had items left over after filling the array, with the filled array
+    TooLong([T; N]),
+}
+
+impl<T, const N: usize> fmt::Display for CollectError<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CollectError::TooShort(partial) => partial.fmt(f),
+            CollectError::TooLong(_) => {
+                write!(f, "more than {} array elements were produced", N)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug, const N: usize> std::error::Error for CollectError<T, N> {}
+
+#[test]
+fn collect_array_test() {
+    let mut iter = 1..6;
+    assert_eq!(collect_array(iter.by_ref()), Ok([1, 2, 3]));
+    assert_eq!(iter.next(), Some(4));
+
+    let partial = collect_array::<_, 4>(vec![String::from("a"), String::from("b")]).unwrap_err();
+    assert_eq!(partial.len(), 2);
+    assert_eq!(partial.as_slice(), ["a", "b"]);
+    assert_eq!(
+        partial.to_string(),
+        "only 2 of 4 array elements were produced"
+    );
+
+    #[derive(Debug, PartialEq)]
+    struct Token(u8);
+    let partial = collect_array::<_, 3>(vec![Token(1), Token(2)]).unwrap_err();
+    let tokens: Vec<Token> = partial.into_iter().collect();
+    assert_eq!(tokens, [Token(1), Token(2)]);
+
+    assert_eq!(collect_array::<_, 0>(0..0), Ok([]));
+}
+
+#[test]
+fn collect_array_exact_test() {
+    assert_eq!(collect_array_exact(1..4), Ok([1, 2, 3]));
+    assert_eq!(
+        collect_array_exact::<_, 2>(1..4),
+        Err(CollectError::TooLong([1, 2]))
+    );
+    match collect_array_exact::<_, 4>(1..4) {
+        Err(CollectError::TooShort(partial)) => assert_eq!(partial.as_slice(), [1, 2, 3]),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn collect_array_drop_test() {
+    use std::rc::Rc;
+
+    let value = Rc::new(());
+    let partial = collect_array::<_, 5>(vec![value.clone(), value.clone()]).unwrap_err();
+    assert_eq!(Rc::strong_count(&value), 3);
+    drop(partial);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
@@ -0,0 +1,59 @@
+use core::fmt;
+
+/// The error returned when a slice is the wrong length to be converted into an array
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LengthError {
+    /// The length of the array that was requested
+    pub expected: usize,
+    /// The length of the slice that was available
+    pub actual: usize,
+    /// The position of the slice within the buffer it was read from, or `None` if it is not known
+    pub offset: Option<usize>,
+}
+
+impl LengthError {
+    pub(crate) fn new(expected: usize, actual: usize) -> Self {
+        LengthError {
+            expected,
+            actual,
+            offset: None,
+        }
+    }
+
+    pub(crate) fn at(self, offset: usize) -> Self {
+        LengthError {
+            offset: Some(offset),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "slice of length {}", self.actual)?;
+        if let Some(offset) = self.offset {
+            write!(f, " at offset {}", offset)?;
+        }
+        write!(
+            f,
+            " cannot be converted to an array of length {}",
+            self.expected
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthError {}
+
+#[test]
+fn length_error_display_test() {
+    let error = LengthError::new(4, 2).at(6);
+    assert_eq!(
+        error.to_string(),
+        "slice of length 2 at offset 6 cannot be converted to an array of length 4"
+    );
+    assert_eq!(
+        LengthError::new(4, 2).to_string(),
+        "slice of length 2 cannot be converted to an array of length 4"
+    );
+}
@@ -0,0 +1,261 @@
+use crate::{
+    array_chunks, array_chunks_mut, array_rchunks, array_windows, slice_to_array,
+    slice_to_array_end, slice_to_array_exact, slice_to_array_mut, slice_to_array_mut_end,
+    slice_to_array_mut_exact, split_to_array, split_to_array_end, split_to_array_end_scan,
+    split_to_array_mut, split_to_array_mut_end, split_to_array_mut_end_scan,
+    split_to_array_mut_scan, split_to_array_scan, try_slice_to_array, try_slice_to_array_mut,
+    try_split_to_array, try_split_to_array_mut, try_split_to_array_mut_scan,
+    try_split_to_array_scan, ArrayChunks, ArrayChunksMut, ArrayRChunks, ArrayWindows, LengthError,
+};
+
+/// Array conversions as methods on slices
+pub trait SliceExt<T> {
+    /// See [`slice_to_array`]
+    fn to_array<const N: usize>(&self) -> Option<&[T; N]>;
+
+    /// See [`slice_to_array_exact`]
+    fn to_array_exact<const N: usize>(&self) -> Option<&[T; N]>;
+
+    /// See [`slice_to_array_end`]
+    fn to_array_end<const N: usize>(&self) -> Option<&[T; N]>;
+
+    /// See [`try_slice_to_array`]
+    fn try_to_array<const N: usize>(&self) -> Result<&[T; N], LengthError>;
+
+    /// See [`split_to_array`]
+    fn split_array<const N: usize>(&self) -> Option<(&[T; N], &[T])>;
+
+    /// See [`split_to_array_end`]
+    fn split_array_end<const N: usize>(&self) -> Option<(&[T], &[T; N])>;
+
+    /// See [`try_split_to_array`]
+    fn try_split_array<const N: usize>(&self) -> Result<(&[T; N], &[T]), LengthError>;
+
+    /// See [`array_chunks`]
+    fn to_array_chunks<const N: usize>(&self) -> ArrayChunks<'_, T, N>;
+
+    /// See [`array_rchunks`]
+    fn to_array_rchunks<const N: usize>(&self) -> ArrayRChunks<'_, T, N>;
+
+    /// See [`array_windows`]
+    fn to_array_windows<const N: usize>(&self) -> ArrayWindows<'_, T, N>;
+}
+
+impl<T> SliceExt<T> for [T] {
+    fn to_array<const N: usize>(&self) -> Option<&[T; N]> {
+        slice_to_array(self)
+    }
+
+    fn to_array_exact<const N: usize>(&self) -> Option<&[T; N]> {
+        slice_to_array_exact(self)
+    }
+
+    fn to_array_end<const N: usize>(&self) -> Option<&[T; N]> {
+        slice_to_array_end(self)
+    }
+
+    fn try_to_array<const N: usize>(&self) -> Result<&[T; N], LengthError> {
+        try_slice_to_array(self)
+    }
+
+    fn split_array<const N: usize>(&self) -> Option<(&[T; N], &[T])> {
+        split_to_array(self)
+    }
+
+    fn split_array_end<const N: usize>(&self) -> Option<(&[T], &[T; N])> {
+        split_to_array_end(self)
+    }
+
+    fn try_split_array<const N: usize>(&self) -> Result<(&[T; N], &[T]), LengthError> {
+        try_split_to_array(self)
+    }
+
+    fn to_array_chunks<const N: usize>(&self) -> ArrayChunks<'_, T, N> {
+        array_chunks(self)
+    }
+
+    fn to_array_rchunks<const N: usize>(&self) -> ArrayRChunks<'_, T, N> {
+        array_rchunks(self)
+    }
+
+    fn to_array_windows<const N: usize>(&self) -> ArrayWindows<'_, T, N> {
+        array_windows(self)
+    }
+}
+
+/// Mutable array conversions as methods on slices
+pub trait SliceMutExt<T> {
+    /// See [`slice_to_array_mut`]
+    fn to_array_mut<const N: usize>(&mut self) -> Option<&mut [T; N]>;
+
+    /// See [`slice_to_array_mut_exact`]
+    fn to_array_mut_exact<const N: usize>(&mut self) -> Option<&mut [T; N]>;
+
+    /// See [`slice_to_array_mut_end`]
+    fn to_array_mut_end<const N: usize>(&mut self) -> Option<&mut [T; N]>;
+
+    /// See [`try_slice_to_array_mut`]
+    fn try_to_array_mut<const N: usize>(&mut self) -> Result<&mut [T; N], LengthError>;
+
+    /// See [`split_to_array_mut`]
+    fn split_to_array_mut<const N: usize>(&mut self) -> Option<(&mut [T; N], &mut [T])>;
+
+    /// See [`split_to_array_mut_end`]
+    fn split_array_mut_end<const N: usize>(&mut self) -> Option<(&mut [T], &mut [T; N])>;
+
+    /// See [`try_split_to_array_mut`]
+    fn try_split_array_mut<const N: usize>(
+        &mut self,
+    ) -> Result<(&mut [T; N], &mut [T]), LengthError>;
+
+    /// See [`array_chunks_mut`]
+    fn to_array_chunks_mut<const N: usize>(&mut self) -> ArrayChunksMut<'_, T, N>;
+}
+
+impl<T> SliceMutExt<T> for [T] {
+    fn to_array_mut<const N: usize>(&mut self) -> Option<&mut [T; N]> {
+        slice_to_array_mut(self)
+    }
+
+    fn to_array_mut_exact<const N: usize>(&mut self) -> Option<&mut [T; N]> {
+        slice_to_array_mut_exact(self)
+    }
+
+    fn to_array_mut_end<const N: usize>(&mut self) -> Option<&mut [T; N]> {
+        slice_to_array_mut_end(self)
+    }
+
+    fn try_to_array_mut<const N: usize>(&mut self) -> Result<&mut [T; N], LengthError> {
+        try_slice_to_array_mut(self)
+    }
+
+    fn split_to_array_mut<const N: usize>(&mut self) -> Option<(&mut [T; N], &mut [T])> {
+        split_to_array_mut(self)
+    }
+
+    fn split_array_mut_end<const N: usize>(&mut self) -> Option<(&mut [T], &mut [T; N])> {
+        split_to_array_mut_end(self)
+    }
+
+    fn try_split_array_mut<const N: usize>(
+        &mut self,
+    ) -> Result<(&mut [T; N], &mut [T]), LengthError> {
+        try_split_to_array_mut(self)
+    }
+
+    fn to_array_chunks_mut<const N: usize>(&mut self) -> ArrayChunksMut<'_, T, N> {
+        array_chunks_mut(self)
+    }
+}
+
+/// Scanning array conversions as methods on slice references, which advance the slice past the
+/// array
+pub trait SliceScanExt<'a, T> {
+    /// See [`split_to_array_scan`]
+    fn take_array<const N: usize>(&mut self) -> Option<&'a [T; N]>;
+
+    /// See [`split_to_array_end_scan`]
+    fn take_array_end<const N: usize>(&mut self) -> Option<&'a [T; N]>;
+
+    /// See [`try_split_to_array_scan`]
+    fn try_take_array<const N: usize>(&mut self) -> Result<&'a [T; N], LengthError>;
+}
+
+impl<'a, T> SliceScanExt<'a, T> for &'a [T] {
+    fn take_array<const N: usize>(&mut self) -> Option<&'a [T; N]> {
+        split_to_array_scan(self)
+    }
+
+    fn take_array_end<const N: usize>(&mut self) -> Option<&'a [T; N]> {
+        split_to_array_end_scan(self)
+    }
+
+    fn try_take_array<const N: usize>(&mut self) -> Result<&'a [T; N], LengthError> {
+        try_split_to_array_scan(self)
+    }
+}
+
+/// Mutable scanning array conversions as methods on mutable slice references, which advance the
+/// slice past the array
+pub trait SliceMutScanExt<'a, T> {
+    /// See [`split_to_array_mut_scan`]
+    fn take_array_mut<const N: usize>(&mut self) -> Option<&'a mut [T; N]>;
+
+    /// See [`split_to_array_mut_end_scan`]
+    fn take_array_mut_end<const N: usize>(&mut self) -> Option<&'a mut [T; N]>;
+
+    /// See [`try_split_to_array_mut_scan`]
+    fn try_take_array_mut<const N: usize>(&mut self) -> Result<&'a mut [T; N], LengthError>;
+}
+
+impl<'a, T> SliceMutScanExt<'a, T> for &'a mut [T] {
+    fn take_array_mut<const N: usize>(&mut self) -> Option<&'a mut [T; N]> {
+        split_to_array_mut_scan(self)
+    }
+
+    fn take_array_mut_end<const N: usize>(&mut self) -> Option<&'a mut [T; N]> {
+        split_to_array_mut_end_scan(self)
+    }
+
+    fn try_take_array_mut<const N: usize>(&mut self) -> Result<&'a mut [T; N], LengthError> {
+        try_split_to_array_mut_scan(self)
+    }
+}
+
+#[test]
+fn slice_ext_test() {
+    let source = &[1, 2, 3, 4, 5][..];
+    assert_eq!(source.to_array(), Some(&[1, 2]));
+    assert_eq!(source.to_array_exact::<4>(), None);
+    assert_eq!(source.to_array_end(), Some(&[4, 5]));
+    assert_eq!(source.try_to_array::<6>(), Err(LengthError::new(6, 5)));
+    assert_eq!(source.split_array(), Some((&[1, 2], &source[2..])));
+    assert_eq!(source.split_array_end(), Some((&source[..3], &[4, 5])));
+    let chunks: ArrayChunks<'_, u8, 2> = source.to_array_chunks();
+    assert_eq!(chunks.remainder(), &[5]);
+    let mut rchunks: ArrayRChunks<'_, u8, 2> = source.to_array_rchunks();
+    assert_eq!(rchunks.next(), Some(&[4, 5]));
+    let windows: ArrayWindows<'_, u8, 4> = source.to_array_windows();
+    assert_eq!(windows.len(), 2);
+}
+
+#[test]
+fn slice_mut_ext_test() {
+    let mut source = [1, 2, 3, 4, 5];
+    let source = &mut source[..];
+    source.to_array_mut::<1>().unwrap()[0] = 10;
+    source.to_array_mut_end::<1>().unwrap()[0] = 50;
+    assert_eq!(source.to_array_mut_exact::<4>(), None);
+    if let Some((head, tail)) = source.split_to_array_mut::<2>() {
+        head[1] = 20;
+        tail[0] = 30;
+    }
+    let chunks: ArrayChunksMut<'_, u8, 2> = source.to_array_chunks_mut();
+    for chunk in chunks {
+        chunk.swap(0, 1);
+    }
+    assert_eq!(source, [20, 10, 4, 30, 50]);
+}
+
+#[test]
+fn slice_scan_ext_test() {
+    let source = [1, 2, 3, 4, 5];
+    let mut source_ref = &source[..];
+    assert_eq!(source_ref.take_array(), Some(&[1, 2]));
+    assert_eq!(source_ref.take_array_end(), Some(&[5]));
+    assert_eq!(
+        source_ref.try_take_array::<3>(),
+        Err(LengthError::new(3, 2))
+    );
+    assert_eq!(source_ref, &[3, 4]);
+
+    let mut source = [1, 2, 3, 4, 5];
+    let mut source_ref = &mut source[..];
+    source_ref.take_array_mut::<2>().unwrap()[0] = 10;
+    source_ref.take_array_mut_end::<1>().unwrap()[0] = 50;
+    assert_eq!(
+        source_ref.try_take_array_mut::<3>(),
+        Err(LengthError::new(3, 2))
+    );
+    assert_eq!(source, [10, 2, 3, 4, 50]);
+}
@@ -0,0 +1,374 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem::{self, MaybeUninit};
+use core::ptr;
+
+/// The initialized prefix of an array of `N` elements that could not be completed
+///
+/// The elements that were produced are dropped along with it.
+pub struct PartialArray<T, const N: usize> {
+    array: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+    pub(crate) fn new() -> Self {
+        PartialArray {
+            array: unsafe { MaybeUninit::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Appends an element, which must not be done once the array is full
+    ///
+    /// Pushing onto a full array panics on the out of bounds index rather than writing past it.
+    pub(crate) fn push_unchecked(&mut self, value: T) {
+        self.array[self.len] = MaybeUninit::new(value);
+        self.len += 1;
+    }
+
+    /// Takes the completed array
+    ///
+    /// # Safety
+    ///
+    /// All `N` elements must have been pushed
+    pub(crate) unsafe fn into_array_unchecked(self) -> [T; N] {
+        assert_unchecked!(
+            self.is_full(),
+            "into_array_unchecked requires all N elements to be pushed"
+        );
+        let this = mem::ManuallyDrop::new(self);
+        unsafe { ptr::read(&this.array as *const [MaybeUninit<T>; N] as *const [T; N]) }
+    }
+
+    /// The number of elements that were produced
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no elements were produced
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The elements that were produced
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { &*(&self.array[..self.len] as *const [MaybeUninit<T>] as *const [T]) }
+    }
+
+    /// The elements that were produced, mutably
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { &mut *(&mut self.array[..self.len] as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) };
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for PartialArray<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PartialArray")
+            .field("elements", &self.as_slice())
+            .field("expected", &N)
+            .finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for PartialArray<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for PartialArray<T, N> {}
+
+impl<T, const N: usize> fmt::Display for PartialArray<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "only {} of {} array elements were produced", self.len, N)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug, const N: usize> std::error::Error for PartialArray<T, N> {}
+
+impl<T, const N: usize> IntoIterator for PartialArray<T, N> {
+    type Item = T;
+    type IntoIter = PartialArrayIntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let this = mem::ManuallyDrop::new(self);
+        PartialArrayIntoIter {
+            array: unsafe { ptr::read(&this.array) },
+            start: 0,
+            end: this.len,
+        }
+    }
+}
+
+/// An iterator that moves the elements out of a [`PartialArray`]
+///
+/// The elements that are not iterated over are dropped along with it.
+pub struct PartialArrayIntoIter<T, const N: usize> {
+    array: [MaybeUninit<T>; N],
+    start: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> PartialArrayIntoIter<T, N> {
+    /// The elements that have not been iterated over yet
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { &*(&self.array[self.start..self.end] as *const [MaybeUninit<T>] as *const [T]) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            &mut *(&mut self.array[self.start..self.end] as *mut [MaybeUninit<T>] as *mut [T])
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for PartialArrayIntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            None
+        } else {
+            self.start += 1;
+            Some(unsafe { self.array[self.start - 1].assume_init_read() })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for PartialArrayIntoIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(unsafe { self.array[self.end].assume_init_read() })
+        }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for PartialArrayIntoIter<T, N> {
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+impl<T, const N: usize> FusedIterator for PartialArrayIntoIter<T, N> {}
+
+impl<T, const N: usize> Drop for PartialArrayIntoIter<T, N> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) };
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for PartialArrayIntoIter<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PartialArrayIntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+/// Builds an array by calling a function with each index
+///
+/// If the function panics, the elements built so far are dropped before unwinding.
+pub fn array_from_fn<T, const N: usize, F: FnMut(usize) -> T>(mut f: F) -> [T; N] {
+    let mut partial = PartialArray::new();
+    while !partial.is_full() {
+        let value = f(partial.len());
+        partial.push_unchecked(value);
+    }
+    unsafe { partial.into_array_unchecked() }
+}
+
+/// Builds an array by calling a fallible function with each index, stopping at the first error
+///
+/// If the function fails or panics, the elements built so far are dropped.
+pub fn try_array_from_fn<T, E, const N: usize, F: FnMut(usize) -> Result<T, E>>(
+    mut f: F,
+) -> Result<[T; N], E> {
+    let mut partial = PartialArray::new();
+    while !partial.is_full() {
+        let value = f(partial.len())?;
+        partial.push_unchecked(value);
+    }
+    Ok(unsafe { partial.into_array_unchecked() })
+}
+
+/// Builds an array by calling an optional function with each index, stopping at the first `None`
+///
+/// If the function fails or panics, the elements built so far are dropped.
+pub fn try_array_from_fn_option<T, const N: usize, F: FnMut(usize) -> Option<T>>(
+    mut f: F,
+) -> Option<[T; N]> {
+    try_array_from_fn(|index| f(index).ok_or(())).ok()
+}
+
+/// Builds an array one element at a time
+///
+/// If the builder is dropped before it is full, the elements pushed so far are dropped with it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ArrayBuilder<T, const N: usize> {
+    partial: PartialArray<T, N>,
+}
+
+impl<T, const N: usize> ArrayBuilder<T, N> {
+    /// Creates an empty builder
+    pub fn new() -> Self {
+        ArrayBuilder {
+            partial: PartialArray::new(),
+        }
+    }
+
+    /// Appends an element to the array
+    ///
+    /// Returns the element if the array is already full
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.partial.is_full() {
+            Err(value)
+        } else {
+            self.partial.push_unchecked(value);
+            Ok(())
+        }
+    }
+
+    /// The number of elements that have been pushed
+    pub fn len(&self) -> usize {
+        self.partial.len()
+    }
+
+    /// Returns `true` if no elements have been pushed
+    pub fn is_empty(&self) -> bool {
+        self.partial.is_empty()
+    }
+
+    /// Returns `true` if `N` elements have been pushed
+    pub fn is_full(&self) -> bool {
+        self.partial.is_full()
+    }
+
+    /// The elements that have been pushed
+    pub fn as_slice(&self) -> &[T] {
+        self.partial.as_slice()
+    }
+
+    /// The elements that have been pushed, mutably
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.partial.as_mut_slice()
+    }
+
+    /// Takes the completed array
+    ///
+    /// Returns the builder if it is not yet full
+    pub fn build(self) -> Result<[T; N], Self> {
+        if self.partial.is_full() {
+            Ok(unsafe { self.partial.into_array_unchecked() })
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ArrayBuilder<T, N> {
+    fn default() -> Self {
+        ArrayBuilder::new()
+    }
+}
+
+#[test]
+fn array_from_fn_test() {
+    let array: [String; 3] = array_from_fn(|index| index.to_string());
+    assert_eq!(array, ["0", "1", "2"]);
+    let empty: [u8; 0] = array_from_fn(|_| unreachable!());
+    assert_eq!(empty, []);
+}
+
+#[test]
+fn try_array_from_fn_test() {
+    use std::rc::Rc;
+
+    let value = Rc::new(());
+    assert_eq!(
+        try_array_from_fn::<_, _, 4, _>(|index| if index < 2 {
+            Ok(value.clone())
+        } else {
+            Err(index)
+        }),
+        Err(2)
+    );
+    assert_eq!(Rc::strong_count(&value), 1);
+    assert_eq!(
+        try_array_from_fn::<_, (), 3, _>(|index| Ok(index * 2)),
+        Ok([0, 2, 4])
+    );
+
+    assert_eq!(
+        try_array_from_fn_option(|index| [1, 2, 3].get(index).copied()),
+        Some([1, 2, 3])
+    );
+    assert_eq!(
+        try_array_from_fn_option::<_, 4, _>(|index| [1, 2, 3].get(index).copied()),
+        None
+    );
+}
+
+#[test]
+fn array_from_fn_panic_test() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let value = Rc::new(());
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        array_from_fn::<_, 5, _>(|index| {
+            if index == 3 {
+                panic!("init failed");
+            }
+            value.clone()
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn array_builder_test() {
+    let mut builder = ArrayBuilder::<String, 2>::new();
+    assert!(builder.is_empty());
+    assert_eq!(builder.push(String::from("a")), Ok(()));
+    assert_eq!(builder.as_slice(), ["a"]);
+    let mut builder = builder.build().unwrap_err();
+    assert_eq!(builder.push(String::from("b")), Ok(()));
+    assert_eq!(builder.push(String::from("c")), Err(String::from("c")));
+    assert!(builder.is_full());
+    builder.as_mut_slice()[0].push('!');
+    assert_eq!(builder.build(), Ok([String::from("a!"), String::from("b")]));
+}
+
+#[test]
+fn array_builder_drop_test() {
+    use std::rc::Rc;
+
+    let value = Rc::new(());
+    let mut builder = ArrayBuilder::<_, 3>::default();
+    builder.push(value.clone()).unwrap();
+    builder.push(value.clone()).unwrap();
+    assert_eq!(builder.len(), 2);
+    drop(builder);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
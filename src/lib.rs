@@ -1,10 +1,80 @@
 // This package contains a number of utility functions, which allow for the easier manipulation of
 // arrays, with functionality such as converting to them from slices.
+//
+// The crate is `no_std`, conversions to and from owned containers require the `alloc` feature, and
+// implementations of `std::error::Error` require the `std` feature.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Asserts the precondition of an unchecked function when debug assertions or the
+/// `strict-unchecked` feature are enabled
+macro_rules! assert_unchecked {
+    ($condition:expr, $message:literal) => {
+        if cfg!(any(debug_assertions, feature = "strict-unchecked")) {
+            assert!($condition, $message);
+        }
+    };
+}
+
+mod array;
+#[cfg(feature = "alloc")]
+mod boxed;
+mod bytes;
+mod cast;
+mod chunks;
+mod collect;
+mod error;
+mod ext;
+mod init;
+mod macros;
+mod matrix;
+mod multi;
+mod owned;
+mod reader;
+mod windows;
+mod writer;
+
+pub use array::{
+    concat_arrays, each_mut, each_ref, enumerate_array, map_array, split_array_owned,
+    split_array_ref, split_array_ref_mut, try_map_array, unzip_array, zip_arrays,
+};
+#[cfg(feature = "alloc")]
+pub use boxed::{boxed_slice_to_array, vec_to_array, vec_to_boxed_array};
+pub use bytes::*;
+pub use cast::{cast_array, cast_array_mut, cast_array_unaligned, CastError, Pod};
+pub use chunks::{
+    array_chunks, array_chunks_mut, array_rchunks, as_chunks, as_chunks_mut, as_rchunks,
+    as_rchunks_mut, flatten, flatten_mut, ArrayChunks, ArrayChunksMut, ArrayRChunks,
+};
+pub use collect::{collect_array, collect_array_exact, CollectError};
+pub use error::LengthError;
+pub use ext::{SliceExt, SliceMutExt, SliceMutScanExt, SliceScanExt};
+pub use init::{
+    array_from_fn, try_array_from_fn, try_array_from_fn_option, ArrayBuilder, PartialArray,
+    PartialArrayIntoIter,
+};
+pub use matrix::{slice_to_matrix, slice_to_matrix_mut, MatrixView};
+pub use multi::{
+    split_to_arrays2, split_to_arrays3, split_to_arrays4, split_to_arrays5, split_to_arrays6,
+    split_to_arrays7, split_to_arrays8,
+};
+pub use owned::{
+    slice_to_array_cloned, slice_to_array_copied, split_to_array_cloned,
+    split_to_array_cloned_scan, split_to_array_copied, split_to_array_copied_scan,
+};
+pub use reader::ArrayReader;
+pub use windows::{array_windows, ArrayWindows};
+pub use writer::ArrayWriter;
 
 /// Turns a slice into a reference to an array
 ///
-/// Returns `None` if `N` is lower than input slice length
-pub fn slice_to_array<T, const N: usize>(source: &[T]) -> Option<&[T; N]> {
+/// Any elements after the first `N` are ignored, use [`slice_to_array_exact`] to reject them.
+///
+/// Returns `None` if `N` is greater than the input slice length
+pub const fn slice_to_array<T, const N: usize>(source: &[T]) -> Option<&[T; N]> {
     if source.len() < N {
         None
     } else {
@@ -14,7 +84,9 @@ pub fn slice_to_array<T, const N: usize>(source: &[T]) -> Option<&[T; N]> {
 
 /// Turns a mutable slice into a mutable reference to an array
 ///
-/// Returns `None` if `N` is lower than the input slice length
+/// Any elements after the first `N` are ignored, use [`slice_to_array_mut_exact`] to reject them.
+///
+/// Returns `None` if `N` is greater than the input slice length
 pub fn slice_to_array_mut<T, const N: usize>(source: &mut [T]) -> Option<&mut [T; N]> {
     if source.len() < N {
         None
@@ -23,20 +95,70 @@ pub fn slice_to_array_mut<T, const N: usize>(source: &mut [T]) -> Option<&mut [T
     }
 }
 
+/// Turns a slice of exactly `N` elements into a reference to an array
+///
+/// Unlike [`slice_to_array`], which takes the first `N` elements of any slice at least `N` long,
+/// this rejects slices with trailing elements.
+///
+/// Returns `None` if `N` is not equal to the input slice length
+pub const fn slice_to_array_exact<T, const N: usize>(source: &[T]) -> Option<&[T; N]> {
+    if source.len() != N {
+        None
+    } else {
+        Some(unsafe { slice_to_array_unchecked(source) })
+    }
+}
+
+/// Turns a mutable slice of exactly `N` elements into a mutable reference to an array
+///
+/// Unlike [`slice_to_array_mut`], which takes the first `N` elements of any slice at least `N`
+/// long, this rejects slices with trailing elements.
+///
+/// Returns `None` if `N` is not equal to the input slice length
+pub fn slice_to_array_mut_exact<T, const N: usize>(source: &mut [T]) -> Option<&mut [T; N]> {
+    if source.len() != N {
+        None
+    } else {
+        Some(unsafe { slice_to_array_mut_unchecked(source) })
+    }
+}
+
 /// Turns a slice into a reference to an array without bounds checking.
-pub unsafe fn slice_to_array_unchecked<T, const N: usize>(source: &[T]) -> &[T; N] {
+///
+/// The length is only checked when debug assertions or the `strict-unchecked` feature are enabled,
+/// in which case a slice that is too short panics.
+///
+/// # Safety
+///
+/// The input slice must have a length of at least `N`
+pub const unsafe fn slice_to_array_unchecked<T, const N: usize>(source: &[T]) -> &[T; N] {
+    assert_unchecked!(
+        source.len() >= N,
+        "slice_to_array_unchecked requires a slice of at least N elements"
+    );
     &*(source.as_ptr() as *const [T; N])
 }
 
 /// Turn a mutable slice into a mutable reference to an array without bounds checking.
+///
+/// The length is only checked when debug assertions or the `strict-unchecked` feature are enabled,
+/// in which case a slice that is too short panics.
+///
+/// # Safety
+///
+/// The input slice must have a length of at least `N`
 pub unsafe fn slice_to_array_mut_unchecked<T, const N: usize>(source: &mut [T]) -> &mut [T; N] {
-    &mut *(source.as_ptr() as *mut [T; N])
+    assert_unchecked!(
+        source.len() >= N,
+        "slice_to_array_mut_unchecked requires a slice of at least N elements"
+    );
+    &mut *(source.as_mut_ptr() as *mut [T; N])
 }
 
 /// Turns a slice into a reference to an array and a slice starting from the end of the array
 ///
 /// Returns `None` if `N` is shorter than the input slice length
-pub fn split_to_array<T, const N: usize>(source: &[T]) -> Option<(&[T; N], &[T])> {
+pub const fn split_to_array<T, const N: usize>(source: &[T]) -> Option<(&[T; N], &[T])> {
     if source.len() < N {
         None
     } else {
@@ -67,6 +189,191 @@ pub fn split_to_array_scan<'a, T, const N: usize>(source: &mut &'a [T]) -> Optio
     })
 }
 
+/// Turn a mutable slice into a mutable reference to an array and mutate the original slice to the
+/// end of the array
+///
+/// Returns `None` if `N` is greater than the input slice length, in which case the original slice
+/// is left unchanged
+pub fn split_to_array_mut_scan<'a, T, const N: usize>(
+    source: &mut &'a mut [T],
+) -> Option<&'a mut [T; N]> {
+    if source.len() < N {
+        None
+    } else {
+        let (head, tail) = core::mem::take(source).split_at_mut(N);
+        *source = tail;
+        Some(unsafe { slice_to_array_mut_unchecked(head) })
+    }
+}
+
+/// Turns the end of a slice into a reference to an array
+///
+/// Returns `None` if `N` is greater than the input slice length
+pub const fn slice_to_array_end<T, const N: usize>(source: &[T]) -> Option<&[T; N]> {
+    match split_to_array_end(source) {
+        Some((_, tail)) => Some(tail),
+        None => None,
+    }
+}
+
+/// Turns the end of a mutable slice into a mutable reference to an array
+///
+/// Returns `None` if `N` is greater than the input slice length
+pub fn slice_to_array_mut_end<T, const N: usize>(source: &mut [T]) -> Option<&mut [T; N]> {
+    split_to_array_mut_end(source).map(|(_, tail)| tail)
+}
+
+/// Turns a slice into a slice ending at the start of the array and a reference to an array taken
+/// from the end of the slice
+///
+/// Returns `None` if `N` is greater than the input slice length
+pub const fn split_to_array_end<T, const N: usize>(source: &[T]) -> Option<(&[T], &[T; N])> {
+    if source.len() < N {
+        None
+    } else {
+        let (head, source) = source.split_at(source.len() - N);
+        Some((head, unsafe { slice_to_array_unchecked(source) }))
+    }
+}
+
+/// Turns a mutable slice into a mutable slice ending at the start of the array and a mutable
+/// reference to an array taken from the end of the slice
+///
+/// Returns `None` if `N` is greater than the input slice length
+pub fn split_to_array_mut_end<T, const N: usize>(
+    source: &mut [T],
+) -> Option<(&mut [T], &mut [T; N])> {
+    if source.len() < N {
+        None
+    } else {
+        let (head, source) = source.split_at_mut(source.len() - N);
+        Some((head, unsafe { slice_to_array_mut_unchecked(source) }))
+    }
+}
+
+/// Turn the end of a slice into a reference to an array and mutate the original slice to the start
+/// of the array
+///
+/// Returns `None` if `N` is greater than the input slice length
+pub fn split_to_array_end_scan<'a, T, const N: usize>(source: &mut &'a [T]) -> Option<&'a [T; N]> {
+    split_to_array_end(source).map(|(head, tail)| {
+        *source = head;
+        tail
+    })
+}
+
+/// Turn the end of a mutable slice into a mutable reference to an array and mutate the original
+/// slice to the start of the array
+///
+/// Returns `None` if `N` is greater than the input slice length, in which case the original slice
+/// is left unchanged
+pub fn split_to_array_mut_end_scan<'a, T, const N: usize>(
+    source: &mut &'a mut [T],
+) -> Option<&'a mut [T; N]> {
+    if source.len() < N {
+        None
+    } else {
+        let (head, tail) = split_to_array_mut_end(core::mem::take(source))?;
+        *source = head;
+        Some(tail)
+    }
+}
+
+/// Turns a slice into a reference to an array
+///
+/// Returns a [`LengthError`] if `N` is greater than the input slice length
+pub fn try_slice_to_array<T, const N: usize>(source: &[T]) -> Result<&[T; N], LengthError> {
+    slice_to_array(source).ok_or_else(|| LengthError::new(N, source.len()))
+}
+
+/// Turns a mutable slice into a mutable reference to an array
+///
+/// Returns a [`LengthError`] if `N` is greater than the input slice length
+pub fn try_slice_to_array_mut<T, const N: usize>(
+    source: &mut [T],
+) -> Result<&mut [T; N], LengthError> {
+    let actual = source.len();
+    slice_to_array_mut(source).ok_or_else(|| LengthError::new(N, actual))
+}
+
+/// Turns a slice of exactly `N` elements into a reference to an array
+///
+/// Returns a [`LengthError`] if `N` is not equal to the input slice length
+pub fn try_slice_to_array_exact<T, const N: usize>(source: &[T]) -> Result<&[T; N], LengthError> {
+    slice_to_array_exact(source).ok_or_else(|| LengthError::new(N, source.len()))
+}
+
+/// Turns a mutable slice of exactly `N` elements into a mutable reference to an array
+///
+/// Returns a [`LengthError`] if `N` is not equal to the input slice length
+pub fn try_slice_to_array_mut_exact<T, const N: usize>(
+    source: &mut [T],
+) -> Result<&mut [T; N], LengthError> {
+    let actual = source.len();
+    slice_to_array_mut_exact(source).ok_or_else(|| LengthError::new(N, actual))
+}
+
+/// Turns a slice into a reference to an array and a slice starting from the end of the array
+///
+/// Returns a [`LengthError`] if `N` is greater than the input slice length
+pub fn try_split_to_array<T, const N: usize>(source: &[T]) -> Result<(&[T; N], &[T]), LengthError> {
+    split_to_array(source).ok_or_else(|| LengthError::new(N, source.len()))
+}
+
+/// Turns a mutable slice into a mutable reference to an array and a mutable slice starting from
+/// the end of the array
+///
+/// Returns a [`LengthError`] if `N` is greater than the input slice length
+pub fn try_split_to_array_mut<T, const N: usize>(
+    source: &mut [T],
+) -> Result<(&mut [T; N], &mut [T]), LengthError> {
+    let actual = source.len();
+    split_to_array_mut(source).ok_or_else(|| LengthError::new(N, actual))
+}
+
+/// Turn a slice into a reference to an array and mutate the original slice to the end of the array
+///
+/// Returns a [`LengthError`] if `N` is greater than the input slice length
+pub fn try_split_to_array_scan<'a, T, const N: usize>(
+    source: &mut &'a [T],
+) -> Result<&'a [T; N], LengthError> {
+    let actual = source.len();
+    split_to_array_scan(source).ok_or_else(|| LengthError::new(N, actual))
+}
+
+/// Turn a mutable slice into a mutable reference to an array and mutate the original slice to the
+/// end of the array
+///
+/// Returns a [`LengthError`] if `N` is greater than the input slice length
+pub fn try_split_to_array_mut_scan<'a, T, const N: usize>(
+    source: &mut &'a mut [T],
+) -> Result<&'a mut [T; N], LengthError> {
+    let actual = source.len();
+    split_to_array_mut_scan(source).ok_or_else(|| LengthError::new(N, actual))
+}
+
+/// Turn the end of a slice into a reference to an array and mutate the original slice to the start
+/// of the array
+///
+/// Returns a [`LengthError`] if `N` is greater than the input slice length
+pub fn try_split_to_array_end_scan<'a, T, const N: usize>(
+    source: &mut &'a [T],
+) -> Result<&'a [T; N], LengthError> {
+    let actual = source.len();
+    split_to_array_end_scan(source).ok_or_else(|| LengthError::new(N, actual))
+}
+
+/// Turn the end of a mutable slice into a mutable reference to an array and mutate the original
+/// slice to the start of the array
+///
+/// Returns a [`LengthError`] if `N` is greater than the input slice length
+pub fn try_split_to_array_mut_end_scan<'a, T, const N: usize>(
+    source: &mut &'a mut [T],
+) -> Result<&'a mut [T; N], LengthError> {
+    let actual = source.len();
+    split_to_array_mut_end_scan(source).ok_or_else(|| LengthError::new(N, actual))
+}
+
 #[test]
 fn slice_to_array_test() {
     let source = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
@@ -99,6 +406,46 @@ fn slice_to_array_mut_test() {
     assert_eq!(source, [1, 2, 3, 4, 5, 6, 100, 8, 9, 10]);
 }
 
+#[test]
+fn slice_to_array_exact_test() {
+    let source = [1, 2, 3, 4, 5];
+    assert_eq!(slice_to_array_exact(&source[..]), Some(&[1, 2, 3, 4, 5]));
+    assert_eq!(slice_to_array_exact(&source[..3]), Some(&[1, 2, 3]));
+    // Prefix semantics accept the oversized slice, exact semantics reject it
+    assert_eq!(slice_to_array::<_, 4>(&source[..]), Some(&[1, 2, 3, 4]));
+    assert_eq!(slice_to_array_exact::<_, 4>(&source[..]), None);
+    assert_eq!(slice_to_array_exact::<_, 6>(&source[..]), None);
+}
+
+#[test]
+fn slice_to_array_mut_exact_test() {
+    let mut source = [1, 2, 3, 4, 5];
+    {
+        if let Some(arr) = slice_to_array_mut_exact::<_, 2>(&mut source[1..3]) {
+            arr[1] = 100;
+        }
+    }
+    assert_eq!(source, [1, 2, 100, 4, 5]);
+    assert_eq!(slice_to_array_mut_exact::<_, 4>(&mut source[..]), None);
+    assert_eq!(slice_to_array_mut_exact::<_, 6>(&mut source[..]), None);
+}
+
+#[test]
+#[cfg(any(debug_assertions, feature = "strict-unchecked"))]
+#[should_panic(expected = "slice_to_array_unchecked requires a slice of at least N elements")]
+fn slice_to_array_unchecked_short_test() {
+    let source = [1, 2, 3];
+    unsafe { slice_to_array_unchecked::<_, 4>(&source[..]) };
+}
+
+#[test]
+#[cfg(any(debug_assertions, feature = "strict-unchecked"))]
+#[should_panic(expected = "slice_to_array_mut_unchecked requires a slice of at least N elements")]
+fn slice_to_array_mut_unchecked_short_test() {
+    let mut source = [1, 2, 3];
+    unsafe { slice_to_array_mut_unchecked::<_, 4>(&mut source[..]) };
+}
+
 #[test]
 fn split_to_array_test() {
     let source = [1, 2, 3, 4, 5];
@@ -122,6 +469,7 @@ fn split_to_array_mut_test() {
 }
 
 #[test]
+#[allow(clippy::toplevel_ref_arg)]
 fn split_to_array_scan_test() {
     let source = [1, 2, 3, 4, 5];
     {
@@ -135,3 +483,185 @@ fn split_to_array_scan_test() {
         assert_eq!(dual, &[4, 5]);
     }
 }
+
+#[test]
+fn split_to_array_mut_scan_test() {
+    let mut source = [1, 2, 3, 4, 5];
+    {
+        let source_ref = &mut &mut source[..];
+        let double: &mut [u8; 2] = split_to_array_mut_scan(source_ref).unwrap();
+        let single: &mut [u8; 1] = split_to_array_mut_scan(source_ref).unwrap();
+        assert_eq!(split_to_array_mut_scan::<_, 3>(source_ref), None);
+        let dual: &mut [u8; 2] = split_to_array_mut_scan(source_ref).unwrap();
+
+        double[0] = 10;
+        single[0] = 30;
+        dual[1] = 50;
+        assert!(source_ref.is_empty());
+    }
+    assert_eq!(source, [10, 2, 30, 4, 50]);
+}
+
+#[test]
+fn slice_to_array_end_test() {
+    let source = [1, 2, 3, 4, 5];
+    assert_eq!(slice_to_array_end(&source[..]), Some(&[3, 4, 5]));
+    assert_eq!(slice_to_array_end(&source[..]), Some(&[1, 2, 3, 4, 5]));
+    assert_eq!(slice_to_array_end::<_, 0>(&source[..]), Some(&[]));
+    assert_eq!(slice_to_array_end::<_, 6>(&source[..]), None);
+}
+
+#[test]
+fn slice_to_array_mut_end_test() {
+    let mut source = [1, 2, 3, 4, 5];
+    {
+        if let Some(arr) = slice_to_array_mut_end::<_, 2>(&mut source[..4]) {
+            arr[0] = 100;
+        }
+    }
+    assert_eq!(source, [1, 2, 100, 4, 5]);
+    assert_eq!(slice_to_array_mut_end::<_, 6>(&mut source[..]), None);
+}
+
+#[test]
+fn split_to_array_end_test() {
+    let source = [1, 2, 3, 4, 5];
+    assert_eq!(
+        split_to_array_end(&source[..]),
+        Some((&source[..2], &[3, 4, 5]))
+    );
+    assert_eq!(split_to_array_end::<_, 6>(&source[..]), None);
+}
+
+#[test]
+fn split_to_array_mut_end_test() {
+    let mut source = [1, 2, 3, 4, 5];
+    {
+        if let Some((start, arr)) = split_to_array_mut_end::<_, 3>(&mut source) {
+            start[1] = 200;
+            arr[1] = 100;
+        }
+    }
+    assert_eq!(source, [1, 200, 3, 100, 5]);
+}
+
+#[test]
+fn split_to_array_end_scan_test() {
+    let source = [1, 2, 3, 4, 5];
+    {
+        let source_ref = &mut &source[..];
+        let double: &[u8; 2] = split_to_array_end_scan(source_ref).unwrap();
+        let single: &[u8; 1] = split_to_array_end_scan(source_ref).unwrap();
+        assert_eq!(split_to_array_end_scan::<_, 3>(source_ref), None);
+        let dual: &[u8; 2] = split_to_array_end_scan(source_ref).unwrap();
+
+        assert_eq!(double, &[4, 5]);
+        assert_eq!(single, &[3]);
+        assert_eq!(dual, &[1, 2]);
+        assert!(source_ref.is_empty());
+    }
+}
+
+#[test]
+fn split_to_array_mut_end_scan_test() {
+    let mut source = [1, 2, 3, 4, 5];
+    {
+        let source_ref = &mut &mut source[..];
+        let double: &mut [u8; 2] = split_to_array_mut_end_scan(source_ref).unwrap();
+        let single: &mut [u8; 1] = split_to_array_mut_end_scan(source_ref).unwrap();
+        assert_eq!(split_to_array_mut_end_scan::<_, 3>(source_ref), None);
+        let dual: &mut [u8; 2] = split_to_array_mut_end_scan(source_ref).unwrap();
+
+        double[1] = 50;
+        single[0] = 30;
+        dual[0] = 10;
+        assert!(source_ref.is_empty());
+    }
+    assert_eq!(source, [10, 2, 30, 4, 50]);
+}
+
+#[test]
+fn try_slice_to_array_test() {
+    let mut source = [1, 2, 3, 4, 5];
+    assert_eq!(try_slice_to_array(&source[..]), Ok(&[1, 2, 3]));
+    assert_eq!(
+        try_slice_to_array::<_, 6>(&source[..]),
+        Err(LengthError::new(6, 5))
+    );
+    assert_eq!(
+        try_slice_to_array_mut::<_, 6>(&mut source[..]),
+        Err(LengthError::new(6, 5))
+    );
+    assert_eq!(
+        try_slice_to_array_exact::<_, 4>(&source[..]),
+        Err(LengthError::new(4, 5))
+    );
+    assert_eq!(
+        try_slice_to_array_mut_exact::<_, 4>(&mut source[..]),
+        Err(LengthError::new(4, 5))
+    );
+    assert_eq!(
+        try_slice_to_array_mut_exact(&mut source[..]),
+        Ok(&mut [1, 2, 3, 4, 5])
+    );
+}
+
+#[test]
+fn try_split_to_array_test() {
+    let mut source = [1, 2, 3, 4, 5];
+    assert_eq!(try_split_to_array(&source[..]), Ok((&[1, 2], &source[2..])));
+    assert_eq!(
+        try_split_to_array::<_, 6>(&source[..]),
+        Err(LengthError::new(6, 5))
+    );
+    assert_eq!(
+        try_split_to_array_mut::<_, 6>(&mut source[..]),
+        Err(LengthError::new(6, 5))
+    );
+}
+
+#[test]
+fn try_split_to_array_scan_test() {
+    fn parse(mut source: &[u8]) -> Result<(u8, [u8; 2]), LengthError> {
+        let [head] = *try_split_to_array_scan(&mut source)?;
+        let tail = *try_split_to_array_end_scan(&mut source)?;
+        Ok((head, tail))
+    }
+    assert_eq!(parse(&[1, 2, 3, 4]), Ok((1, [3, 4])));
+    assert_eq!(parse(&[1, 2]), Err(LengthError::new(2, 1)));
+    assert_eq!(parse(&[]), Err(LengthError::new(1, 0)));
+
+    let mut source = [1, 2, 3];
+    let source_ref = &mut &mut source[..];
+    assert_eq!(try_split_to_array_mut_scan(source_ref), Ok(&mut [1]));
+    assert_eq!(try_split_to_array_mut_end_scan(source_ref), Ok(&mut [3]));
+    assert_eq!(
+        try_split_to_array_mut_scan::<_, 2>(source_ref),
+        Err(LengthError::new(2, 1))
+    );
+    assert_eq!(
+        try_split_to_array_mut_end_scan::<_, 2>(source_ref),
+        Err(LengthError::new(2, 1))
+    );
+}
+
+#[test]
+fn const_conversion_test() {
+    const TABLE: [u8; 6] = [1, 2, 3, 4, 5, 6];
+    // `Option::unwrap` is not const on the minimum supported compiler
+    const HEADER: Option<&[u8; 4]> = slice_to_array(&TABLE);
+    const WHOLE: Option<&[u8; 6]> = slice_to_array_exact(&TABLE);
+    const TOO_LONG: Option<&[u8; 7]> = slice_to_array(&TABLE);
+    const FOOTER: Option<&[u8; 2]> = slice_to_array_end(&TABLE);
+    const SPLIT: Option<(&[u8; 1], &[u8])> = split_to_array(&TABLE);
+    const SPLIT_END: Option<(&[u8], &[u8; 3])> = split_to_array_end(&TABLE);
+    const UNCHECKED: &[u8; 3] = unsafe { slice_to_array_unchecked(&TABLE) };
+
+    assert_eq!(HEADER, Some(&[1, 2, 3, 4]));
+    assert_eq!(WHOLE, Some(&TABLE));
+    assert_eq!(TOO_LONG, None);
+    assert_eq!(FOOTER, Some(&[5, 6]));
+    assert_eq!(SPLIT, Some((&[1], &TABLE[1..])));
+    assert_eq!(SPLIT_END, Some((&TABLE[..3], &[4, 5, 6])));
+    assert_eq!(UNCHECKED, &[1, 2, 3]);
+}
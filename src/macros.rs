@@ -0,0 +1,179 @@
+/// Builds an array by evaluating an expression for each index
+///
+/// ```
+/// let squares: [usize; 4] = arrutil::arr![i * i for i in 0..4];
+/// assert_eq!(squares, [0, 1, 4, 9]);
+/// ```
+///
+/// Expands to [`array_from_fn`](crate::array_from_fn), so if the expression panics, the elements
+/// built so far are dropped before unwinding.
+///
+/// The expression is collected eight token trees at a time, so an expression of more than around
+/// a thousand top level tokens reaches the recursion limit. Wrapping such an expression in
+/// parentheses makes it a single token tree.
+#[macro_export]
+macro_rules! arr {
+    (@munch [$($body:tt)*] for $index:pat in 0..$len:expr) => {
+        $crate::array_from_fn::<_, { $len }, _>(|$index| ($($body)*))
+    };
+    (@munch [$($body:tt)*] $a:tt for $($tail:tt)*) => {
+        $crate::arr!(@munch [$($body)* $a] for $($tail)*)
+    };
+    (@munch [$($body:tt)*] $a:tt $b:tt for $($tail:tt)*) => {
+        $crate::arr!(@munch [$($body)* $a $b] for $($tail)*)
+    };
+    (@munch [$($body:tt)*] $a:tt $b:tt $c:tt for $($tail:tt)*) => {
+        $crate::arr!(@munch [$($body)* $a $b $c] for $($tail)*)
+    };
+    (@munch [$($body:tt)*] $a:tt $b:tt $c:tt $d:tt for $($tail:tt)*) => {
+        $crate::arr!(@munch [$($body)* $a $b $c $d] for $($tail)*)
+    };
+    (@munch [$($body:tt)*] $a:tt $b:tt $c:tt $d:tt $e:tt for $($tail:tt)*) => {
+        $crate::arr!(@munch [$($body)* $a $b $c $d $e] for $($tail)*)
+    };
+    (@munch [$($body:tt)*] $a:tt $b:tt $c:tt $d:tt $e:tt $f:tt for $($tail:tt)*) => {
+        $crate::arr!(@munch [$($body)* $a $b $c $d $e $f] for $($tail)*)
+    };
+    (@munch [$($body:tt)*] $a:tt $b:tt $c:tt $d:tt $e:tt $f:tt $g:tt for $($tail:tt)*) => {
+        $crate::arr!(@munch [$($body)* $a $b $c $d $e $f $g] for $($tail)*)
+    };
+    (@munch [$($body:tt)*] $a:tt $b:tt $c:tt $d:tt $e:tt $f:tt $g:tt $h:tt $($tail:tt)*) => {
+        $crate::arr!(@munch [$($body)* $a $b $c $d $e $f $g $h] $($tail)*)
+    };
+    (@munch [$($body:tt)*] $next:tt $($tail:tt)*) => {
+        $crate::arr!(@munch [$($body)* $next] $($tail)*)
+    };
+    ($($tokens:tt)+) => {
+        $crate::arr!(@munch [] $($tokens)+)
+    };
+}
+
+/// Turns an indexable value into a reference to an array of the given length
+///
+/// Expands to [`slice_to_array`](crate::slice_to_array) over the whole of the value, so the
+/// element type does not need to be written out.
+///
+/// ```
+/// let buf = vec![1u8, 2, 3, 4, 5];
+/// assert_eq!(arrutil::arr_from_slice!(buf, 4), Some(&[1, 2, 3, 4]));
+/// ```
+///
+/// Evaluates to `None` if the length is greater than the length of the value
+#[macro_export]
+macro_rules! arr_from_slice {
+    ($source:expr, $len:expr) => {
+        $crate::slice_to_array::<_, { $len }>(&$source[..])
+    };
+}
+
+/// Destructures the start of an indexable value into references to several arrays, binding each
+/// to a name, with an optional final name bound to the rest of the value, and panics if the value
+/// is too short
+///
+/// ```
+/// let buf = [1u8, 2, 3, 4, 5, 6, 7];
+/// arrutil::split_arr!(buf => a: 2, b: 4, rest);
+/// assert_eq!(a, &[1, 2]);
+/// assert_eq!(b, &[3, 4, 5, 6]);
+/// assert_eq!(rest, &[7]);
+/// ```
+///
+/// Use [`split_to_arrays2`](crate::split_to_arrays2) and the other `split_to_arraysN` functions
+/// to split without panicking.
+///
+/// # Panics
+///
+/// Panics if the value is shorter than the total length of the arrays
+#[macro_export]
+macro_rules! split_arr {
+    (@munch $source:ident; $name:ident : $len:expr, $($tail:tt)*) => {
+        let ($name, $source) = $crate::split_to_array::<_, { $len }>($source)
+            .expect("slice too short to split into arrays");
+        $crate::split_arr!(@munch $source; $($tail)*);
+    };
+    (@munch $source:ident; $name:ident : $len:expr) => {
+        $crate::split_arr!(@munch $source; $name: $len,);
+    };
+    (@munch $source:ident; $rest:ident) => {
+        let $rest = $source;
+    };
+    (@munch $source:ident;) => {
+        let _ = $source;
+    };
+    ($source:expr => $($tail:tt)*) => {
+        let source = &$source[..];
+        $crate::split_arr!(@munch source; $($tail)*);
+    };
+}
+
+#[test]
+fn arr_test() {
+    let squares: [usize; 5] = arr![i * i for i in 0..5];
+    assert_eq!(squares, [0, 1, 4, 9, 16]);
+
+    let strings = arr![format!("{}", i + 1) for i in 0..3];
+    assert_eq!(strings, ["1", "2", "3"]);
+
+    let empty: [u8; 0] = arr![0 for _ in 0..0];
+    assert_eq!(empty, []);
+
+    // Longer than the default recursion limit of 128 token trees
+    let sums = arr![(i as u32) + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1
+        + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1
+        + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1
+        + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1
+        for i in 0..3];
+    assert_eq!(sums, [83, 84, 85]);
+}
+
+#[test]
+fn arr_panic_test() {
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    struct Tracked<'a>(&'a Cell<usize>);
+    impl Drop for Tracked<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let dropped = Cell::new(0);
+    let result = catch_unwind(AssertUnwindSafe(
+        || arr![if i == 3 { panic!("init failed") } else { Tracked(&dropped) } for i in 0..5],
+    ));
+    assert!(result.is_err());
+    assert_eq!(dropped.get(), 3);
+}
+
+#[test]
+fn arr_from_slice_test() {
+    let source: Vec<u8> = (1..=5).collect();
+    assert_eq!(arr_from_slice!(source, 4), Some(&[1, 2, 3, 4]));
+    assert_eq!(arr_from_slice!(source, 6), None);
+    const LEN: usize = 2;
+    assert_eq!(arr_from_slice!(&source[3..], LEN), Some(&[4, 5]));
+}
+
+#[test]
+fn split_arr_test() {
+    let source = [1, 2, 3, 4, 5, 6, 7];
+    split_arr!(source => a: 2, b: 4, rest);
+    assert_eq!(a, &[1, 2]);
+    assert_eq!(b, &[3, 4, 5, 6]);
+    assert_eq!(rest, &[7]);
+
+    split_arr!(source => c: 3, d: 1);
+    assert_eq!(c, &[1, 2, 3]);
+    assert_eq!(d, &[4]);
+
+    split_arr!(&source[5..] => e: 2,);
+    assert_eq!(e, &[6, 7]);
+}
+
+#[test]
+#[should_panic]
+fn split_arr_short_test() {
+    let source = [1, 2, 3];
+    split_arr!(source => _a: 2, _b: 2);
+}
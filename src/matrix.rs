@@ -0,0 +1,189 @@
+use crate::{array_from_fn, slice_to_array_unchecked};
+
+/// Turns a slice into a reference to a matrix of `R` rows of `C` elements, laid out row by row
+///
+/// Returns `None` if `R * C` is greater than the input slice length
+pub fn slice_to_matrix<T, const R: usize, const C: usize>(source: &[T]) -> Option<&[[T; C]; R]> {
+    match R.checked_mul(C) {
+        Some(len) if len <= source.len() => {
+            Some(unsafe { &*(source.as_ptr() as *const [[T; C]; R]) })
+        }
+        _ => None,
+    }
+}
+
+/// Turns a mutable slice into a mutable reference to a matrix of `R` rows of `C` elements, laid out
+/// row by row
+///
+/// Returns `None` if `R * C` is greater than the input slice length
+pub fn slice_to_matrix_mut<T, const R: usize, const C: usize>(
+    source: &mut [T],
+) -> Option<&mut [[T; C]; R]> {
+    match R.checked_mul(C) {
+        Some(len) if len <= source.len() => {
+            Some(unsafe { &mut *(source.as_mut_ptr() as *mut [[T; C]; R]) })
+        }
+        _ => None,
+    }
+}
+
+/// A view of `R` rows of `C` elements over a slice, where each row starts `stride` elements after
+/// the previous one
+///
+/// A stride greater than `C` allows viewing a sub-matrix of a larger matrix.
+#[derive(Debug)]
+pub struct MatrixView<'a, T, const R: usize, const C: usize> {
+    source: &'a [T],
+    stride: usize,
+}
+
+impl<T, const R: usize, const C: usize> Clone for MatrixView<'_, T, R, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const R: usize, const C: usize> Copy for MatrixView<'_, T, R, C> {}
+
+impl<'a, T, const R: usize, const C: usize> MatrixView<'a, T, R, C> {
+    /// Creates a view over a slice with rows laid out contiguously
+    ///
+    /// Returns `None` if `R * C` is greater than the input slice length
+    pub fn new(source: &'a [T]) -> Option<Self> {
+        MatrixView::with_stride(source, C)
+    }
+
+    /// Creates a view over a slice with each row starting `stride` elements after the previous one
+    ///
+    /// Returns `None` if `stride` is less than `C`, or if the last row would end past the end of
+    /// the input slice
+    pub fn with_stride(source: &'a [T], stride: usize) -> Option<Self> {
+        if stride < C {
+            return None;
+        }
+        let len = match R {
+            0 => 0,
+            _ => (R - 1).checked_mul(stride)?.checked_add(C)?,
+        };
+        if source.len() < len {
+            None
+        } else {
+            Some(MatrixView { source, stride })
+        }
+    }
+
+    /// The number of elements between the start of each row
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Returns a reference to the element at a row and column
+    ///
+    /// Returns `None` if the row or column is out of range
+    pub fn get(&self, row: usize, column: usize) -> Option<&'a T> {
+        self.row(row)?.get(column)
+    }
+
+    /// Returns a reference to a row
+    ///
+    /// Returns `None` if the row is out of range
+    pub fn row(&self, row: usize) -> Option<&'a [T; C]> {
+        if row < R {
+            Some(self.row_unchecked(row))
+        } else {
+            None
+        }
+    }
+
+    /// Returns references to each element of a column
+    ///
+    /// Returns `None` if the column is out of range
+    pub fn column(&self, column: usize) -> Option<[&'a T; R]> {
+        if column < C {
+            Some(self.column_unchecked(column))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the rows
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T; C]> + ExactSizeIterator {
+        let view = *self;
+        (0..R).map(move |row| view.row_unchecked(row))
+    }
+
+    /// Returns an iterator over the columns, which are the rows of the transposed matrix
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = [&'a T; R]> + ExactSizeIterator {
+        let view = *self;
+        (0..C).map(move |column| view.column_unchecked(column))
+    }
+
+    /// Copies the elements into a matrix of `C` rows of `R` elements
+    pub fn transpose(&self) -> [[T; R]; C]
+    where
+        T: Clone,
+    {
+        array_from_fn(|column| array_from_fn(|row| self.row_unchecked(row)[column].clone()))
+    }
+
+    fn row_unchecked(&self, row: usize) -> &'a [T; C] {
+        unsafe { slice_to_array_unchecked(&self.source[row * self.stride..]) }
+    }
+
+    fn column_unchecked(&self, column: usize) -> [&'a T; R] {
+        array_from_fn(|row| &self.row_unchecked(row)[column])
+    }
+}
+
+#[test]
+fn slice_to_matrix_test() {
+    let source = [1, 2, 3, 4, 5, 6, 7];
+    assert_eq!(slice_to_matrix(&source[..]), Some(&[[1, 2, 3], [4, 5, 6]]));
+    assert_eq!(slice_to_matrix(&source[..]), Some(&[[1], [2], [3]]));
+    assert_eq!(slice_to_matrix::<_, 2, 4>(&source[..]), None);
+    assert_eq!(slice_to_matrix::<_, { usize::MAX }, 2>(&[(); 7][..]), None);
+    assert_eq!(slice_to_matrix::<_, 0, 8>(&source[..]), Some(&[]));
+}
+
+#[test]
+fn slice_to_matrix_mut_test() {
+    let mut source = [1, 2, 3, 4, 5, 6];
+    {
+        let matrix: &mut [[u8; 2]; 3] = slice_to_matrix_mut(&mut source[..]).unwrap();
+        matrix[1][1] = 40;
+        matrix[2] = [50, 60];
+    }
+    assert_eq!(source, [1, 2, 3, 40, 50, 60]);
+    assert_eq!(slice_to_matrix_mut::<_, 7, 1>(&mut source[..]), None);
+}
+
+#[test]
+fn matrix_view_test() {
+    let source = [1, 2, 3, 4, 5, 6];
+    let view = MatrixView::<_, 2, 3>::new(&source[..]).unwrap();
+    assert_eq!(view.get(1, 2), Some(&6));
+    assert_eq!(view.get(2, 0), None);
+    assert_eq!(view.row(0), Some(&[1, 2, 3]));
+    assert_eq!(view.column(1), Some([&2, &5]));
+    assert_eq!(view.column(3), None);
+    assert_eq!(
+        view.rows().rev().collect::<Vec<_>>(),
+        [&[4, 5, 6], &[1, 2, 3]]
+    );
+    assert_eq!(view.columns().len(), 3);
+    assert_eq!(view.transpose(), [[1, 4], [2, 5], [3, 6]]);
+    assert!(MatrixView::<_, 3, 3>::new(&source[..]).is_none());
+}
+
+#[test]
+fn matrix_view_stride_test() {
+    // The top right 2x2 corner of a 3x4 matrix
+    let source = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+    let view = MatrixView::<_, 2, 2>::with_stride(&source[2..], 4).unwrap();
+    assert_eq!(view.stride(), 4);
+    assert_eq!(view.rows().collect::<Vec<_>>(), [&[3, 4], &[7, 8]]);
+    assert_eq!(view.columns().collect::<Vec<_>>(), [[&3, &7], [&4, &8]]);
+    assert!(MatrixView::<_, 2, 2>::with_stride(&source[..], 1).is_none());
+    assert!(MatrixView::<_, 3, 2>::with_stride(&source[3..], 4).is_none());
+    assert!(MatrixView::<_, 3, 2>::with_stride(&source[2..], 4).is_some());
+}
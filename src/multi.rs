@@ -0,0 +1,69 @@
+use crate::slice_to_array_unchecked;
+
+macro_rules! split_to_arrays {
+    ($($name:ident: $count:literal, $($len:ident $head:ident),+;)*) => {
+        $(
+            #[doc = concat!(
+                "Turns a slice into references to ",
+                $count,
+                " consecutive arrays and a slice starting from the end of the last array"
+            )]
+            ///
+            /// The total length is checked once, before any of the arrays are split off.
+            ///
+            /// Returns `None` if the total length of the arrays is greater than the input slice
+            /// length
+            #[allow(clippy::type_complexity)]
+            pub fn $name<T, $(const $len: usize),+>(
+                source: &[T],
+            ) -> Option<($(&[T; $len],)+ &[T])> {
+                let total = [$($len),+]
+                    .iter()
+                    .try_fold(0usize, |total, len| total.checked_add(*len))?;
+                if source.len() < total {
+                    None
+                } else {
+                    $(let ($head, source) = source.split_at($len);)+
+                    Some(($(unsafe { slice_to_array_unchecked($head) },)+ source))
+                }
+            }
+        )*
+    };
+}
+
+split_to_arrays! {
+    split_to_arrays2: "2", A a, B b;
+    split_to_arrays3: "3", A a, B b, C c;
+    split_to_arrays4: "4", A a, B b, C c, D d;
+    split_to_arrays5: "5", A a, B b, C c, D d, E e;
+    split_to_arrays6: "6", A a, B b, C c, D d, E e, F f;
+    split_to_arrays7: "7", A a, B b, C c, D d, E e, F f, G g;
+    split_to_arrays8: "8", A a, B b, C c, D d, E e, F f, G g, H h;
+}
+
+#[test]
+fn split_to_arrays_test() {
+    let source = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+    let (magic, version, flags, len, tail) = split_to_arrays4::<_, 4, 2, 2, 4>(&source).unwrap();
+    assert_eq!(magic, &[1, 2, 3, 4]);
+    assert_eq!(version, &[5, 6]);
+    assert_eq!(flags, &[7, 8]);
+    assert_eq!(len, &[9, 10, 11, 12]);
+    assert_eq!(tail, &[13]);
+
+    assert_eq!(
+        split_to_arrays2(&source[..3]),
+        Some((&[1], &[2, 3], &source[3..3]))
+    );
+    assert_eq!(split_to_arrays3::<_, 4, 4, 6>(&source), None);
+}
+
+#[test]
+fn split_to_arrays_overflow_test() {
+    let source = [(); 4];
+    assert_eq!(split_to_arrays2::<_, { usize::MAX }, 2>(&source[..]), None);
+    assert_eq!(
+        split_to_arrays8::<_, 0, 1, 0, 1, 0, 1, 0, 1>(&source[..]),
+        Some((&[], &[()], &[], &[()], &[], &[()], &[], &[()], &source[4..]))
+    );
+}
@@ -0,0 +1,154 @@
+use crate::{split_to_array, split_to_array_scan};
+
+/// Clones the start of a slice into an array
+///
+/// If cloning an element panics, the elements cloned so far are dropped before unwinding.
+///
+/// Returns `None` if `N` is greater than the input slice length
+pub fn slice_to_array_cloned<T: Clone, const N: usize>(source: &[T]) -> Option<[T; N]> {
+    split_to_array_cloned(source).map(|(head, _)| head)
+}
+
+/// Copies the start of a slice into an array
+///
+/// Returns `None` if `N` is greater than the input slice length
+pub fn slice_to_array_copied<T: Copy, const N: usize>(source: &[T]) -> Option<[T; N]> {
+    split_to_array_copied(source).map(|(head, _)| head)
+}
+
+/// Clones the start of a slice into an array, and returns it with a slice starting from the end of
+/// the array
+///
+/// If cloning an element panics, the elements cloned so far are dropped before unwinding.
+///
+/// Returns `None` if `N` is greater than the input slice length
+pub fn split_to_array_cloned<T: Clone, const N: usize>(source: &[T]) -> Option<([T; N], &[T])> {
+    split_to_array(source).map(|(head, tail)| (head.clone(), tail))
+}
+
+/// Copies the start of a slice into an array, and returns it with a slice starting from the end of
+/// the array
+///
+/// Returns `None` if `N` is greater than the input slice length
+pub fn split_to_array_copied<T: Copy, const N: usize>(source: &[T]) -> Option<([T; N], &[T])> {
+    split_to_array(source).map(|(head, tail)| (*head, tail))
+}
+
+/// Clones the start of a slice into an array and mutates the original slice to the end of the
+/// array
+///
+/// If cloning an element panics, the elements cloned so far are dropped before unwinding, and the
+/// original slice is left unchanged.
+///
+/// Returns `None` if `N` is greater than the input slice length
+pub fn split_to_array_cloned_scan<T: Clone, const N: usize>(source: &mut &[T]) -> Option<[T; N]> {
+    let mut remaining = *source;
+    let head = split_to_array_scan::<_, N>(&mut remaining)?.clone();
+    *source = remaining;
+    Some(head)
+}
+
+/// Copies the start of a slice into an array and mutates the original slice to the end of the
+/// array
+///
+/// Returns `None` if `N` is greater than the input slice length
+pub fn split_to_array_copied_scan<T: Copy, const N: usize>(source: &mut &[T]) -> Option<[T; N]> {
+    split_to_array_scan(source).copied()
+}
+
+#[test]
+fn slice_to_array_cloned_test() {
+    let source = vec![String::from("a"), String::from("b"), String::from("c")];
+    let owned: [String; 2] = slice_to_array_cloned(&source).unwrap();
+    drop(source);
+    assert_eq!(owned, [String::from("a"), String::from("b")]);
+    assert_eq!(slice_to_array_cloned::<String, 1>(&[]), None);
+}
+
+#[test]
+fn slice_to_array_copied_test() {
+    let source = [1, 2, 3, 4, 5];
+    assert_eq!(slice_to_array_copied(&source[..]), Some([1, 2, 3]));
+    assert_eq!(slice_to_array_copied::<_, 6>(&source[..]), None);
+}
+
+#[test]
+fn split_to_array_owned_test() {
+    let source = [1, 2, 3, 4, 5];
+    assert_eq!(
+        split_to_array_cloned(&source[..]),
+        Some(([1, 2], &source[2..]))
+    );
+    assert_eq!(
+        split_to_array_copied(&source[..]),
+        Some(([1, 2, 3], &source[3..]))
+    );
+    assert_eq!(split_to_array_cloned::<_, 6>(&source[..]), None);
+    assert_eq!(split_to_array_copied::<_, 6>(&source[..]), None);
+}
+
+#[test]
+fn split_to_array_owned_scan_test() {
+    let source = [1, 2, 3, 4, 5];
+    let source_ref = &mut &source[..];
+    assert_eq!(split_to_array_cloned_scan(source_ref), Some([1, 2]));
+    assert_eq!(split_to_array_copied_scan(source_ref), Some([3]));
+    assert_eq!(split_to_array_copied_scan::<_, 3>(source_ref), None);
+    assert_eq!(split_to_array_cloned_scan::<_, 3>(source_ref), None);
+    assert_eq!(source_ref, &[4, 5]);
+}
+
+#[test]
+fn split_to_array_cloned_panic_test() {
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    struct Tracked<'a> {
+        live: &'a Cell<isize>,
+        panics: bool,
+    }
+    impl Clone for Tracked<'_> {
+        fn clone(&self) -> Self {
+            if self.panics {
+                panic!("clone failed");
+            }
+            self.live.set(self.live.get() + 1);
+            Tracked {
+                live: self.live,
+                panics: false,
+            }
+        }
+    }
+    impl Drop for Tracked<'_> {
+        fn drop(&mut self) {
+            self.live.set(self.live.get() - 1);
+        }
+    }
+
+    let live = Cell::new(0);
+    {
+        let source = [
+            Tracked {
+                live: &live,
+                panics: false,
+            },
+            Tracked {
+                live: &live,
+                panics: false,
+            },
+            Tracked {
+                live: &live,
+                panics: true,
+            },
+        ];
+        live.set(3);
+        let source_ref = &mut &source[..];
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            split_to_array_cloned_scan::<_, 3>(source_ref)
+        }));
+        assert!(result.is_err());
+        assert_eq!(live.get(), 3);
+        assert_eq!(source_ref.len(), 3);
+    }
+    assert_eq!(live.get(), 0);
+}
@@ -0,0 +1,152 @@
+use crate::{slice_to_array, split_to_array_scan, LengthError};
+
+/// A cursor over a slice, which reads successive arrays from the front of the remaining slice
+///
+/// Tracks its position in the underlying slice, so that it can be rewound to an earlier point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArrayReader<'a, T> {
+    source: &'a [T],
+    position: usize,
+}
+
+impl<'a, T> ArrayReader<'a, T> {
+    /// Creates a new reader positioned at the start of the slice
+    pub fn new(source: &'a [T]) -> Self {
+        ArrayReader {
+            source,
+            position: 0,
+        }
+    }
+
+    /// The number of elements that have been consumed from the underlying slice
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The part of the underlying slice that has not yet been consumed
+    pub fn remaining(&self) -> &'a [T] {
+        &self.source[self.position..]
+    }
+
+    /// Returns `true` if there are no elements left to read
+    pub fn is_empty(&self) -> bool {
+        self.position == self.source.len()
+    }
+
+    /// Reads a reference to an array from the front of the remaining slice, advancing the reader
+    ///
+    /// Returns `None` if `N` is greater than the remaining slice length, in which case the reader
+    /// is not advanced
+    pub fn take<const N: usize>(&mut self) -> Option<&'a [T; N]> {
+        let mut remaining = self.remaining();
+        let head = split_to_array_scan(&mut remaining)?;
+        self.position += N;
+        Some(head)
+    }
+
+    /// Reads a reference to an array from the front of the remaining slice, advancing the reader
+    ///
+    /// Returns a [`LengthError`] at the current position if `N` is greater than the remaining
+    /// slice length, in which case the reader is not advanced
+    pub fn try_take<const N: usize>(&mut self) -> Result<&'a [T; N], LengthError> {
+        let position = self.position;
+        let actual = self.remaining().len();
+        self.take()
+            .ok_or_else(|| LengthError::new(N, actual).at(position))
+    }
+
+    /// Reads a reference to an array from the front of the remaining slice without advancing the
+    /// reader
+    ///
+    /// Returns `None` if `N` is greater than the remaining slice length
+    pub fn peek<const N: usize>(&self) -> Option<&'a [T; N]> {
+        slice_to_array(self.remaining())
+    }
+
+    /// Advances the reader by `n` elements, returning the skipped elements
+    ///
+    /// Returns `None` if `n` is greater than the remaining slice length, in which case the reader
+    /// is not advanced
+    pub fn skip(&mut self, n: usize) -> Option<&'a [T]> {
+        let remaining = self.remaining();
+        if remaining.len() < n {
+            None
+        } else {
+            self.position += n;
+            Some(&remaining[..n])
+        }
+    }
+
+    /// Moves the reader to an absolute position in the underlying slice
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is greater than the length of the underlying slice
+    pub fn rewind_to(&mut self, position: usize) {
+        assert!(
+            position <= self.source.len(),
+            "position {} out of range for slice of length {}",
+            position,
+            self.source.len()
+        );
+        self.position = position;
+    }
+}
+
+impl<'a, T> From<&'a [T]> for ArrayReader<'a, T> {
+    fn from(source: &'a [T]) -> Self {
+        ArrayReader::new(source)
+    }
+}
+
+#[test]
+fn array_reader_take_test() {
+    let source = [1, 2, 3, 4, 5];
+    let mut reader = ArrayReader::new(&source[..]);
+    assert_eq!(reader.take(), Some(&[1, 2]));
+    assert_eq!(reader.position(), 2);
+    assert_eq!(reader.take::<4>(), None);
+    assert_eq!(reader.position(), 2);
+    assert_eq!(reader.take(), Some(&[3, 4, 5]));
+    assert!(reader.is_empty());
+    assert_eq!(reader.remaining(), &[]);
+}
+
+#[test]
+fn array_reader_peek_skip_test() {
+    let source = [1, 2, 3, 4, 5];
+    let mut reader = ArrayReader::from(&source[..]);
+    assert_eq!(reader.peek(), Some(&[1, 2, 3]));
+    assert_eq!(reader.position(), 0);
+    assert_eq!(reader.skip(2), Some(&source[..2]));
+    assert_eq!(reader.skip(4), None);
+    assert_eq!(reader.peek(), Some(&[3]));
+    assert_eq!(reader.remaining(), &[3, 4, 5]);
+}
+
+#[test]
+fn array_reader_rewind_test() {
+    let source = [1, 2, 3, 4, 5];
+    let mut reader = ArrayReader::new(&source[..]);
+    let _: &[u8; 4] = reader.take().unwrap();
+    reader.rewind_to(1);
+    assert_eq!(reader.take(), Some(&[2, 3]));
+    reader.rewind_to(5);
+    assert!(reader.is_empty());
+}
+
+#[test]
+fn array_reader_try_take_test() {
+    let source = [1, 2, 3, 4, 5];
+    let mut reader = ArrayReader::new(&source[..]);
+    assert_eq!(reader.try_take(), Ok(&[1, 2]));
+    assert_eq!(reader.try_take::<4>(), Err(LengthError::new(4, 3).at(2)));
+}
+
+#[test]
+#[should_panic]
+fn array_reader_rewind_out_of_range_test() {
+    let source = [1, 2, 3];
+    let mut reader = ArrayReader::new(&source[..]);
+    reader.rewind_to(4);
+}
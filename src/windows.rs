@@ -0,0 +1,127 @@
+use core::iter::FusedIterator;
+
+use crate::slice_to_array_unchecked;
+
+/// Returns an iterator over references to all overlapping `N` element arrays of a slice
+///
+/// # Panics
+///
+/// Panics if `N` is zero
+pub fn array_windows<T, const N: usize>(source: &[T]) -> ArrayWindows<'_, T, N> {
+    assert!(N != 0, "window size must be non-zero");
+    ArrayWindows { source }
+}
+
+/// An iterator over references to all overlapping `N` element arrays of a slice
+///
+/// Created by [`array_windows`].
+#[derive(Debug)]
+pub struct ArrayWindows<'a, T, const N: usize> {
+    source: &'a [T],
+}
+
+impl<T, const N: usize> Clone for ArrayWindows<'_, T, N> {
+    fn clone(&self) -> Self {
+        ArrayWindows { ..*self }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ArrayWindows<'a, T, N> {
+    type Item = &'a [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.source.len() < N {
+            None
+        } else {
+            let window = unsafe { slice_to_array_unchecked(self.source) };
+            self.source = &self.source[1..];
+            Some(window)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if self.len() <= n {
+            self.source = &[];
+            None
+        } else {
+            self.source = &self.source[n..];
+            self.next()
+        }
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ArrayWindows<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.source.len() < N {
+            None
+        } else {
+            let start = self.source.len() - N;
+            let window = unsafe { slice_to_array_unchecked(&self.source[start..]) };
+            self.source = &self.source[..self.source.len() - 1];
+            Some(window)
+        }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayWindows<'_, T, N> {
+    fn len(&self) -> usize {
+        if self.source.len() < N {
+            0
+        } else {
+            self.source.len() - N + 1
+        }
+    }
+}
+
+impl<T, const N: usize> FusedIterator for ArrayWindows<'_, T, N> {}
+
+#[test]
+fn array_windows_test() {
+    let source = [1, 2, 3, 4, 5];
+    let mut windows = array_windows::<_, 3>(&source[..]);
+    assert_eq!(windows.len(), 3);
+    assert_eq!(windows.next(), Some(&[1, 2, 3]));
+    assert_eq!(windows.next_back(), Some(&[3, 4, 5]));
+    assert_eq!(windows.len(), 1);
+    assert_eq!(windows.clone().next_back(), Some(&[2, 3, 4]));
+    assert_eq!(windows.next(), Some(&[2, 3, 4]));
+    assert_eq!(windows.next(), None);
+    assert_eq!(windows.next_back(), None);
+
+    let sums: Vec<u8> = array_windows(&source[..]).map(|[a, b]| a + b).collect();
+    assert_eq!(sums, [3, 5, 7, 9]);
+    assert_eq!(array_windows::<_, 6>(&source[..]).len(), 0);
+    assert_eq!(array_windows::<_, 5>(&source[..]).count(), 1);
+}
+
+#[test]
+fn array_windows_zst_test() {
+    let source = &[(); usize::MAX][..];
+    let mut windows = array_windows::<_, 2>(source);
+    assert_eq!(windows.len(), usize::MAX - 1);
+    assert_eq!(windows.size_hint(), (usize::MAX - 1, Some(usize::MAX - 1)));
+    assert_eq!(windows.next(), Some(&[(), ()]));
+    assert_eq!(windows.nth(usize::MAX - 4), Some(&[(), ()]));
+    assert_eq!(windows.len(), 1);
+    assert_eq!(array_windows::<_, 1>(source).len(), usize::MAX);
+}
+
+#[test]
+fn array_windows_nth_test() {
+    let source = [1, 2, 3, 4, 5];
+    let mut windows = array_windows::<_, 2>(&source[..]);
+    assert_eq!(windows.nth(2), Some(&[3, 4]));
+    assert_eq!(windows.nth(1), None);
+    assert_eq!(windows.next(), None);
+}
+
+#[test]
+#[should_panic]
+fn array_windows_zero_test() {
+    array_windows::<u8, 0>(&[1, 2, 3]);
+}
@@ -0,0 +1,130 @@
+use crate::{split_to_array_mut_scan, LengthError};
+
+/// A cursor over a mutable slice, which hands out successive disjoint arrays from the front of the
+/// remaining slice
+///
+/// Tracks how many elements have been handed out, so that the written length of an output buffer
+/// can be recovered once it has been filled.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ArrayWriter<'a, T> {
+    source: &'a mut [T],
+    written: usize,
+}
+
+impl<'a, T> ArrayWriter<'a, T> {
+    /// Creates a new writer positioned at the start of the slice
+    pub fn new(source: &'a mut [T]) -> Self {
+        ArrayWriter { source, written: 0 }
+    }
+
+    /// The number of elements that have been handed out from the underlying slice
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// The number of elements that have not yet been handed out
+    pub fn remaining_len(&self) -> usize {
+        self.source.len()
+    }
+
+    /// Returns `true` if there are no elements left to hand out
+    pub fn is_empty(&self) -> bool {
+        self.source.is_empty()
+    }
+
+    /// The part of the underlying slice that has not yet been handed out
+    pub fn remaining(&mut self) -> &mut [T] {
+        self.source
+    }
+
+    /// Consumes the writer, returning the part of the underlying slice that has not yet been
+    /// handed out
+    pub fn into_remaining(self) -> &'a mut [T] {
+        self.source
+    }
+
+    /// Takes a mutable reference to an array from the front of the remaining slice, advancing the
+    /// writer
+    ///
+    /// Returns `None` if `N` is greater than the remaining slice length, in which case the writer
+    /// is not advanced
+    pub fn take<const N: usize>(&mut self) -> Option<&'a mut [T; N]> {
+        let head = split_to_array_mut_scan(&mut self.source)?;
+        self.written += N;
+        Some(head)
+    }
+
+    /// Takes a mutable reference to an array from the front of the remaining slice, advancing the
+    /// writer
+    ///
+    /// Returns a [`LengthError`] at the current position if `N` is greater than the remaining
+    /// slice length, in which case the writer is not advanced
+    pub fn try_take<const N: usize>(&mut self) -> Result<&'a mut [T; N], LengthError> {
+        let written = self.written;
+        let actual = self.source.len();
+        self.take()
+            .ok_or_else(|| LengthError::new(N, actual).at(written))
+    }
+
+    /// Advances the writer by `n` elements, returning the skipped elements
+    ///
+    /// Returns `None` if `n` is greater than the remaining slice length, in which case the writer
+    /// is not advanced
+    pub fn skip(&mut self, n: usize) -> Option<&'a mut [T]> {
+        if self.source.len() < n {
+            None
+        } else {
+            let (head, tail) = core::mem::take(&mut self.source).split_at_mut(n);
+            self.source = tail;
+            self.written += n;
+            Some(head)
+        }
+    }
+}
+
+impl<'a, T> From<&'a mut [T]> for ArrayWriter<'a, T> {
+    fn from(source: &'a mut [T]) -> Self {
+        ArrayWriter::new(source)
+    }
+}
+
+#[test]
+fn array_writer_take_test() {
+    let mut source = [0; 5];
+    {
+        let mut writer = ArrayWriter::new(&mut source[..]);
+        let head: &mut [u8; 2] = writer.take().unwrap();
+        let tail: &mut [u8; 3] = writer.take().unwrap();
+        *head = [1, 2];
+        *tail = [3, 4, 5];
+        assert_eq!(writer.written(), 5);
+        assert!(writer.is_empty());
+        assert_eq!(writer.take::<1>(), None);
+    }
+    assert_eq!(source, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn array_writer_try_take_test() {
+    let mut source = [0; 3];
+    let mut writer = ArrayWriter::new(&mut source[..]);
+    assert_eq!(writer.try_take(), Ok(&mut [0, 0]));
+    assert_eq!(writer.try_take::<2>(), Err(LengthError::new(2, 1).at(2)));
+}
+
+#[test]
+fn array_writer_skip_test() {
+    let mut source = [0; 5];
+    {
+        let mut writer = ArrayWriter::from(&mut source[..]);
+        assert_eq!(writer.skip(6), None);
+        writer.skip(1).unwrap()[0] = 1;
+        assert_eq!(writer.take::<5>(), None);
+        writer.take::<2>().unwrap()[1] = 3;
+        assert_eq!(writer.written(), 3);
+        assert_eq!(writer.remaining_len(), 2);
+        writer.remaining()[0] = 4;
+        writer.into_remaining()[1] = 5;
+    }
+    assert_eq!(source, [1, 0, 3, 4, 5]);
+}
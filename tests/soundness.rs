@@ -0,0 +1,317 @@
+// Exercises every function backed by unsafe code, including zero sized types, empty arrays and
+// arrays spanning the whole slice. Run under Miri to check for undefined behaviour with
+// `cargo +nightly miri test --test soundness`.
+
+use std::rc::Rc;
+
+use arrutil::*;
+
+#[test]
+fn unchecked_conversions() {
+    let mut source = [1u16, 2, 3];
+    unsafe {
+        assert_eq!(slice_to_array_unchecked::<_, 3>(&source[..]), &[1, 2, 3]);
+        assert_eq!(slice_to_array_unchecked::<_, 0>(&source[3..]), &[]);
+        slice_to_array_mut_unchecked::<_, 3>(&mut source[..])[2] = 30;
+        slice_to_array_mut_unchecked::<_, 1>(&mut source[1..])[0] = 20;
+        assert_eq!(
+            slice_to_array_mut_unchecked::<_, 0>(&mut source[3..]),
+            &mut []
+        );
+    }
+    assert_eq!(source, [1, 20, 30]);
+
+    let mut zsts = [(); 4];
+    unsafe {
+        assert_eq!(slice_to_array_unchecked::<_, 4>(&zsts[..]), &[(); 4]);
+        assert_eq!(
+            slice_to_array_mut_unchecked::<_, 4>(&mut zsts[..]),
+            &mut [(); 4]
+        );
+    }
+}
+
+#[test]
+fn mutable_reference_is_unique() {
+    // Writing through the array and then through the original slice must not invalidate either
+    let mut source = [0u8; 4];
+    let slice = &mut source[..];
+    let array: &mut [u8; 4] = slice_to_array_mut(slice).unwrap();
+    array[0] = 1;
+    array[3] = 4;
+    slice[1] = 2;
+    assert_eq!(source, [1, 2, 0, 4]);
+}
+
+#[test]
+fn checked_conversions() {
+    let mut source = [1u32, 2, 3];
+    assert_eq!(slice_to_array::<_, 3>(&source[..]), Some(&[1, 2, 3]));
+    assert_eq!(slice_to_array_exact::<_, 3>(&source[..]), Some(&[1, 2, 3]));
+    assert_eq!(slice_to_array_end::<_, 3>(&source[..]), Some(&[1, 2, 3]));
+    assert_eq!(
+        slice_to_array_mut_exact::<_, 0>(&mut source[..0]),
+        Some(&mut [])
+    );
+    slice_to_array_mut::<_, 3>(&mut source[..]).unwrap()[0] = 10;
+    slice_to_array_mut_end::<_, 3>(&mut source[..]).unwrap()[2] = 30;
+    assert_eq!(source, [10, 2, 30]);
+
+    let (head, tail) = split_to_array::<_, 3>(&source[..]).unwrap();
+    assert_eq!((head, tail), (&[10, 2, 30], &[][..]));
+    let (head, tail) = split_to_array_end::<_, 0>(&source[..]).unwrap();
+    assert_eq!((head, tail), (&source[..], &[]));
+    {
+        let (head, tail) = split_to_array_mut::<_, 2>(&mut source[..]).unwrap();
+        head[1] = 20;
+        tail[0] = 3;
+    }
+    {
+        let (head, tail) = split_to_array_mut_end::<_, 3>(&mut source[..]).unwrap();
+        assert!(head.is_empty());
+        tail[0] = 1;
+    }
+    assert_eq!(source, [1, 20, 3]);
+
+    let zsts = [(); 3];
+    assert_eq!(slice_to_array::<_, 3>(&zsts[..]), Some(&[(); 3]));
+    assert_eq!(
+        split_to_array_end::<_, 3>(&zsts[..]),
+        Some((&[][..], &[(); 3]))
+    );
+}
+
+#[test]
+fn scanning_conversions() {
+    let mut source = [1u64, 2, 3, 4];
+    {
+        let source_ref = &mut &source[..];
+        assert_eq!(split_to_array_scan::<_, 0>(source_ref), Some(&[]));
+        assert_eq!(split_to_array_end_scan::<_, 1>(source_ref), Some(&[4]));
+        assert_eq!(split_to_array_scan::<_, 3>(source_ref), Some(&[1, 2, 3]));
+        assert!(source_ref.is_empty());
+    }
+    {
+        let source_ref = &mut &mut source[..];
+        let head: &mut [u64; 1] = split_to_array_mut_scan(source_ref).unwrap();
+        let tail: &mut [u64; 3] = split_to_array_mut_end_scan(source_ref).unwrap();
+        assert!(source_ref.is_empty());
+        head[0] = 10;
+        tail[2] = 40;
+    }
+    assert_eq!(source, [10, 2, 3, 40]);
+
+    let mut zsts = [(); 2];
+    let source_ref = &mut &mut zsts[..];
+    assert!(split_to_array_mut_scan::<_, 2>(source_ref).is_some());
+    assert!(split_to_array_mut_end_scan::<_, 1>(source_ref).is_none());
+}
+
+#[test]
+fn cursors() {
+    let mut source = [1u8, 2, 3];
+    {
+        let mut writer = ArrayWriter::new(&mut source[..]);
+        let head: &mut [u8; 1] = writer.take().unwrap();
+        let tail: &mut [u8; 2] = writer.take().unwrap();
+        head[0] = 10;
+        tail[1] = 30;
+        assert!(writer.take::<0>().is_some());
+    }
+    let mut reader = ArrayReader::new(&source[..]);
+    assert_eq!(reader.take::<3>(), Some(&[10, 2, 30]));
+    reader.rewind_to(1);
+    assert_eq!(reader.peek::<2>(), Some(&[2, 30]));
+}
+
+#[test]
+fn owned_conversions() {
+    let value = Rc::new(());
+    let source = [value.clone(), value.clone()];
+    let cloned: [Rc<()>; 2] = slice_to_array_cloned(&source[..]).unwrap();
+    assert_eq!(Rc::strong_count(&value), 5);
+    drop(cloned);
+    let source_ref = &mut &source[..];
+    let _: [Rc<()>; 0] = split_to_array_cloned_scan(source_ref).unwrap();
+    let _: [Rc<()>; 2] = split_to_array_cloned_scan(source_ref).unwrap();
+    drop(source);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed_conversions() {
+    let value = Rc::new(());
+    let boxed: Box<[Rc<()>]> = vec![value.clone(), value.clone()].into_boxed_slice();
+    let array: Box<[Rc<()>; 2]> = boxed_slice_to_array(boxed).unwrap();
+    drop(array);
+
+    let mut vec = Vec::with_capacity(4);
+    vec.push(value.clone());
+    let array: Box<[Rc<()>; 1]> = vec_to_boxed_array(vec).unwrap();
+    drop(array);
+
+    let array: [Rc<()>; 2] = vec_to_array(vec![value.clone(), value.clone()]).unwrap();
+    drop(array);
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    let empty: Box<[(); 0]> = vec_to_boxed_array(Vec::new()).unwrap();
+    assert_eq!(*empty, []);
+    let zsts: [(); 3] = vec_to_array(vec![(); 3]).unwrap();
+    assert_eq!(zsts, [(); 3]);
+    let zsts: Box<[(); 3]> = boxed_slice_to_array(vec![(); 3].into_boxed_slice()).unwrap();
+    assert_eq!(*zsts, [(); 3]);
+}
+
+#[test]
+fn chunk_iterators() {
+    let mut source = [1u8, 2, 3, 4, 5];
+    assert_eq!(array_chunks::<_, 5>(&source[..]).count(), 1);
+    assert_eq!(array_rchunks::<_, 2>(&source[..]).rev().count(), 2);
+    for chunk in array_chunks_mut::<_, 2>(&mut source[..]).rev() {
+        chunk[0] *= 10;
+    }
+    assert_eq!(source, [10, 2, 30, 4, 5]);
+
+    let zsts = [(); 5];
+    assert_eq!(array_chunks::<_, 2>(&zsts[..]).len(), 2);
+    assert_eq!(array_windows::<_, 5>(&zsts[..]).count(), 1);
+    assert_eq!(array_windows::<_, 2>(&source[..]).rev().count(), 4);
+}
+
+#[test]
+fn nested_arrays() {
+    let mut source = [1u16, 2, 3, 4, 5];
+    let (rows, remainder) = as_chunks::<_, 2>(&source[..]);
+    assert_eq!((rows, remainder), (&[[1, 2], [3, 4]][..], &[5][..]));
+    assert_eq!(flatten(rows), &source[..4]);
+    let (remainder, rows) = as_rchunks::<_, 5>(&source[..]);
+    assert_eq!((remainder, rows), (&[][..], &[[1, 2, 3, 4, 5]][..]));
+    {
+        let (rows, remainder) = as_chunks_mut::<_, 2>(&mut source[..]);
+        flatten_mut(rows)[0] = 10;
+        remainder[0] = 50;
+    }
+    {
+        let (remainder, rows) = as_rchunks_mut::<_, 4>(&mut source[..]);
+        remainder[0] += 1;
+        rows[0][0] = 20;
+    }
+    assert_eq!(source, [11, 20, 3, 4, 50]);
+
+    let zsts = [[(); 3]; 2];
+    assert_eq!(flatten(&zsts).len(), 6);
+    assert_eq!(as_chunks::<_, 4>(flatten(&zsts)).0.len(), 1);
+}
+
+#[test]
+fn matrices() {
+    let mut source = [1i8, 2, 3, 4, 5, 6];
+    assert_eq!(
+        slice_to_matrix::<_, 2, 3>(&source[..]),
+        Some(&[[1, 2, 3], [4, 5, 6]])
+    );
+    assert_eq!(slice_to_matrix::<_, 0, 0>(&source[..0]), Some(&[]));
+    slice_to_matrix_mut::<_, 3, 2>(&mut source[..]).unwrap()[2][1] = 60;
+    let view = MatrixView::<_, 2, 1>::with_stride(&source[..], 5).unwrap();
+    assert_eq!(view.column(0), Some([&1, &60]));
+    assert_eq!(view.transpose(), [[1, 60]]);
+
+    let zsts = [(); 4];
+    assert_eq!(slice_to_matrix::<_, 2, 2>(&zsts[..]), Some(&[[(); 2]; 2]));
+}
+
+#[test]
+fn owned_arrays() {
+    let value = Rc::new(());
+    let joined: [Rc<()>; 3] = concat_arrays([value.clone()], [value.clone(), value.clone()]);
+    let (head, tail): ([Rc<()>; 0], [Rc<()>; 3]) = split_array_owned(joined);
+    drop((head, tail));
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    let mut source = [1u8, 2, 3];
+    assert_eq!(split_array_ref::<_, 3, 3>(&source), (&[1, 2, 3], &[][..]));
+    split_array_ref_mut::<_, 3, 0>(&mut source).1[0] = 10;
+    assert_eq!(source, [10, 2, 3]);
+
+    let joined: [(); 5] = concat_arrays([(); 2], [(); 3]);
+    let _: ([(); 1], [(); 4]) = split_array_owned(joined);
+}
+
+#[test]
+fn array_construction() {
+    let value = Rc::new(());
+    let array: [Rc<()>; 3] = array_from_fn(|_| value.clone());
+    drop(array);
+    let result: Result<[Rc<()>; 3], ()> = try_array_from_fn(|index| {
+        if index < 2 {
+            Ok(value.clone())
+        } else {
+            Err(())
+        }
+    });
+    assert!(result.is_err());
+    let partial = collect_array::<_, 4>(vec![value.clone(), value.clone()]).unwrap_err();
+    assert_eq!(partial.as_slice().len(), 2);
+    drop(partial);
+    let partial = collect_array::<_, 4>(vec![value.clone(), value.clone(), value.clone()]);
+    let mut elements = partial.unwrap_err().into_iter();
+    let first = elements.next().unwrap();
+    let last = elements.next_back().unwrap();
+    assert_eq!(elements.as_slice().len(), 1);
+    drop((first, last, elements));
+    let mut builder = ArrayBuilder::<_, 2>::new();
+    builder.push(value.clone()).unwrap();
+    builder.as_mut_slice()[0] = value.clone();
+    drop(builder);
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    let zsts: [(); 3] = array_from_fn(|_| ());
+    assert_eq!(collect_array::<_, 3>(zsts.iter().copied()), Ok(zsts));
+    let empty: [Rc<()>; 0] = collect_array(std::iter::empty()).unwrap();
+    assert_eq!(empty.len(), 0);
+}
+
+#[test]
+fn combinators() {
+    let value = Rc::new(());
+    let array = map_array([1, 2, 3], |_| value.clone());
+    let mut calls = 0;
+    let result = try_map_array(array, |value| {
+        calls += 1;
+        if calls < 2 {
+            Ok(value)
+        } else {
+            Err(())
+        }
+    });
+    assert!(result.is_err());
+    let (left, right) = unzip_array(zip_arrays([value.clone()], [value.clone()]));
+    drop((left, right));
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    let mut source = [1u8, 2];
+    for element in each_mut(&mut source) {
+        *element += 1;
+    }
+    assert_eq!(each_ref(&source), [&2, &3]);
+    assert_eq!(enumerate_array([(); 2]), [(0, ()), (1, ())]);
+}
+
+#[test]
+fn casts() {
+    let mut source = [0u64; 3];
+    // Reinterpret the backing storage of an aligned array as bytes
+    let bytes = unsafe { std::slice::from_raw_parts_mut(source.as_mut_ptr() as *mut u8, 24) };
+    cast_array_mut::<u32, 6>(bytes).unwrap()[5] = 7;
+    cast_array_mut::<u16, 0>(&mut bytes[24..]).unwrap();
+    assert_eq!(cast_array::<u32, 6>(bytes).unwrap()[5], 7);
+    assert_eq!(
+        cast_array::<[u8; 4], 6>(bytes).unwrap()[5],
+        7u32.to_ne_bytes()
+    );
+    assert!(cast_array::<u64, 1>(&bytes[4..]).is_err());
+    assert_eq!(cast_array_unaligned::<u32, 1>(&bytes[20..]), Ok([7]));
+    assert_eq!(cast_array_unaligned::<u64, 2>(&bytes[3..]), Ok([0, 0]));
+    assert_eq!(cast_array_unaligned::<u8, 0>(&[]), Ok([]));
+}